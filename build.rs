@@ -0,0 +1,37 @@
+//! Detects features of the Rust compiler that are newer than this crate's MSRV.
+
+use std::env;
+use std::process::Command;
+
+fn rustc_minor_version() -> Option<u32> {
+    let rustc = env::var_os("RUSTC")?;
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    let version = String::from_utf8(output.stdout).ok()?;
+
+    // e.g. "rustc 1.81.0 (eeb90cda1 2024-09-04)"
+    let mut parts = version.split_whitespace().nth(1)?.split('.');
+    if parts.next()? != "1" {
+        return None;
+    }
+
+    parts.next()?.parse().ok()
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let minor = match rustc_minor_version() {
+        Some(minor) => minor,
+        None => return,
+    };
+
+    // Check-cfg is only supported since Rust 1.80
+    if minor >= 80 {
+        println!("cargo:rustc-check-cfg=cfg(selfie_core_error)");
+    }
+
+    // core::error::Error is stable since Rust 1.81
+    if minor >= 81 {
+        println!("cargo:rustc-cfg=selfie_core_error");
+    }
+}
@@ -0,0 +1,159 @@
+//! Test-support utilities to check that some code does not perform any allocation.
+//!
+//! This module provides a [`CountingAllocator`], a global allocator wrapper which counts the
+//! allocator calls (allocations, deallocations and reallocations) made by each thread, as well as
+//! the [`count_allocator_calls`] and [`assert_no_alloc`] helpers to measure them.
+//!
+//! The [`CountingAllocator`] must be installed as the global allocator (usually in a test
+//! binary) for these helpers to work.
+//!
+//! This module is only available with the `alloc-guard` feature enabled.
+//!
+//! # Example
+//!
+//! ```
+//! use std::pin::Pin;
+//! use selfie::alloc_guard::{assert_no_alloc, CountingAllocator};
+//! use selfie::refs::Ref;
+//! use selfie::Selfie;
+//!
+//! #[global_allocator]
+//! static ALLOCATOR: CountingAllocator = CountingAllocator::system();
+//!
+//! let data = "Hello, world!".to_owned();
+//!
+//! let hello = assert_no_alloc(|| {
+//!     let selfie: Selfie<&String, Ref<str>> = Selfie::new(Pin::new(&data), |s| &s[0..5]);
+//!     selfie.with_referential(|r| r.len())
+//! });
+//!
+//! assert_eq!(5, hello);
+//! ```
+
+extern crate std;
+
+use core::alloc::{GlobalAlloc, Layout};
+use core::cell::Cell;
+use std::alloc::System;
+
+std::thread_local! {
+    static ALLOCATOR_CALLS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[inline]
+fn record_allocator_call() {
+    // This may fail if called during the thread's destruction, in which case it is not counted.
+    let _ = ALLOCATOR_CALLS.try_with(|calls| calls.set(calls.get() + 1));
+}
+
+/// A global allocator wrapper, which counts the allocator calls made by each thread before
+/// forwarding them to the inner allocator `A`.
+///
+/// See the [module documentation](self) for an example.
+pub struct CountingAllocator<A = System> {
+    inner: A,
+}
+
+impl CountingAllocator<System> {
+    /// Creates a new [`CountingAllocator`] wrapping the [`System`] allocator.
+    #[inline]
+    pub const fn system() -> Self {
+        Self { inner: System }
+    }
+}
+
+impl<A> CountingAllocator<A> {
+    /// Creates a new [`CountingAllocator`] wrapping the given allocator.
+    #[inline]
+    pub const fn new(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+// SAFETY: all calls are forwarded as-is to the inner allocator.
+#[allow(unsafe_code)]
+unsafe impl<A: GlobalAlloc> GlobalAlloc for CountingAllocator<A> {
+    #[inline]
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record_allocator_call();
+        self.inner.alloc(layout)
+    }
+
+    #[inline]
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        record_allocator_call();
+        self.inner.dealloc(ptr, layout)
+    }
+
+    #[inline]
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record_allocator_call();
+        self.inner.alloc_zeroed(layout)
+    }
+
+    #[inline]
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record_allocator_call();
+        self.inner.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Returns the total number of allocator calls made by the current thread so far.
+///
+/// This always returns `0` if the [`CountingAllocator`] is not installed as the global allocator.
+#[inline]
+pub fn allocator_calls() -> usize {
+    ALLOCATOR_CALLS.with(|calls| calls.get())
+}
+
+/// Runs the given closure, and returns its result alongside the number of allocator calls it
+/// made on the current thread.
+///
+/// # Example
+///
+/// ```
+/// use selfie::alloc_guard::{count_allocator_calls, CountingAllocator};
+///
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator = CountingAllocator::system();
+///
+/// let (_, calls) = count_allocator_calls(|| drop(Box::new(42)));
+/// assert_eq!(2, calls); // One allocation, one deallocation
+/// ```
+#[inline]
+pub fn count_allocator_calls<T, F: FnOnce() -> T>(f: F) -> (T, usize) {
+    let before = allocator_calls();
+    let result = f();
+    let after = allocator_calls();
+
+    (result, after - before)
+}
+
+/// Runs the given closure and returns its result, asserting it did not make any allocator call
+/// on the current thread.
+///
+/// # Panics
+///
+/// Panics if the closure made any allocator call.
+///
+/// # Example
+///
+/// ```should_panic
+/// use selfie::alloc_guard::{assert_no_alloc, CountingAllocator};
+///
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator = CountingAllocator::system();
+///
+/// assert_no_alloc(|| Box::new(42)); // Panics!
+/// ```
+#[inline]
+#[track_caller]
+pub fn assert_no_alloc<T, F: FnOnce() -> T>(f: F) -> T {
+    let (result, calls) = count_allocator_calls(f);
+    assert_eq!(
+        0, calls,
+        "Expected no allocator calls, but {} were made",
+        calls
+    );
+    result
+}
@@ -0,0 +1,179 @@
+//! Support for [`basedrop`](::basedrop)'s realtime-safe smart pointers as the owned pointer of a
+//! [`Selfie`](crate::Selfie) or [`SelfieMut`](crate::SelfieMut).
+//!
+//! Dropping a [`Selfie`](crate::Selfie) using one of these owners does not perform any allocator
+//! call: the owned data is only pushed to the drop queue of its [`Collector`](::basedrop::Collector),
+//! and actually dropped once the collector is run (usually on another, non-realtime thread).
+//! This makes them suitable for self-referential structs dropped on a realtime audio thread, as
+//! long as the referential type does not allocate or deallocate either.
+//!
+//! This module is only available with the `basedrop` feature enabled.
+
+#![allow(unsafe_code)] // Implementing StableDeref is inherently unsafe
+
+use ::basedrop::{Handle, Owned, Shared};
+use core::fmt::{Debug, Formatter};
+use core::ops::{Deref, DerefMut};
+use stable_deref_trait::{CloneStableDeref, StableDeref};
+
+/// An owned pointer with deferred collection, usable as the owned pointer of a
+/// [`Selfie`](crate::Selfie) or [`SelfieMut`](crate::SelfieMut).
+///
+/// This is a thin wrapper around [`basedrop::Owned`](Owned).
+///
+/// # Example
+///
+/// ```
+/// use std::pin::Pin;
+/// use basedrop::Collector;
+/// use selfie::basedrop::OwnedOwner;
+/// use selfie::refs::Ref;
+/// use selfie::Selfie;
+///
+/// let mut collector = Collector::new();
+/// let data = OwnedOwner::new(&collector.handle(), "Hello, world!".to_owned());
+///
+/// let selfie: Selfie<OwnedOwner<String>, Ref<str>> = Selfie::new(Pin::new(data), |s| &s[0..5]);
+/// assert_eq!("Hello", selfie.with_referential(|r| *r));
+///
+/// drop(selfie); // The String is not dropped yet
+/// assert_eq!(1, collector.alloc_count());
+///
+/// collector.collect(); // The String is dropped now
+/// assert_eq!(0, collector.alloc_count());
+/// # assert!(collector.try_cleanup().is_ok());
+/// ```
+pub struct OwnedOwner<T>(Owned<T>);
+
+impl<T: Send + 'static> OwnedOwner<T> {
+    /// Allocates a new [`Owned`] pointer with the given collector [`Handle`], and wraps it.
+    #[inline]
+    pub fn new(handle: &Handle, data: T) -> Self {
+        Self(Owned::new(handle, data))
+    }
+}
+
+impl<T> OwnedOwner<T> {
+    /// Unwraps this owner, returning the inner [`Owned`] pointer.
+    #[inline]
+    pub fn into_inner(self) -> Owned<T> {
+        self.0
+    }
+}
+
+impl<T> From<Owned<T>> for OwnedOwner<T> {
+    #[inline]
+    fn from(owned: Owned<T>) -> Self {
+        Self(owned)
+    }
+}
+
+impl<T> Deref for OwnedOwner<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for OwnedOwner<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+// SAFETY: the data is allocated in a collector node, which does not move when the pointer is moved.
+unsafe impl<T> StableDeref for OwnedOwner<T> {}
+
+impl<T: Debug> Debug for OwnedOwner<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.0.deref().fmt(f)
+    }
+}
+
+/// A reference-counted pointer with deferred collection, usable as the owned pointer of a
+/// [`Selfie`](crate::Selfie).
+///
+/// This is a thin wrapper around [`basedrop::Shared`](Shared). Because cloning it does not clone
+/// the data itself, it can be used with [`Selfie::map_cloned`](crate::Selfie::map_cloned).
+///
+/// # Example
+///
+/// ```
+/// use std::pin::Pin;
+/// use basedrop::Collector;
+/// use selfie::basedrop::SharedOwner;
+/// use selfie::refs::Ref;
+/// use selfie::Selfie;
+///
+/// let mut collector = Collector::new();
+/// let data = SharedOwner::new(&collector.handle(), "Hello, world!".to_owned());
+///
+/// let selfie: Selfie<SharedOwner<String>, Ref<str>> = Selfie::new(Pin::new(data), |s| &s[0..5]);
+/// let second_selfie = selfie.map_cloned::<Ref<str>, _>(|s, _| &s[3..]);
+///
+/// drop(selfie);
+/// collector.collect();
+/// assert_eq!("lo", second_selfie.with_referential(|r| *r)); // Still alive
+///
+/// drop(second_selfie);
+/// collector.collect();
+/// assert_eq!(0, collector.alloc_count());
+/// # assert!(collector.try_cleanup().is_ok());
+/// ```
+pub struct SharedOwner<T>(Shared<T>);
+
+impl<T: Send + 'static> SharedOwner<T> {
+    /// Allocates a new [`Shared`] pointer with the given collector [`Handle`], and wraps it.
+    #[inline]
+    pub fn new(handle: &Handle, data: T) -> Self {
+        Self(Shared::new(handle, data))
+    }
+}
+
+impl<T> SharedOwner<T> {
+    /// Unwraps this owner, returning the inner [`Shared`] pointer.
+    #[inline]
+    pub fn into_inner(self) -> Shared<T> {
+        self.0
+    }
+}
+
+impl<T> From<Shared<T>> for SharedOwner<T> {
+    #[inline]
+    fn from(shared: Shared<T>) -> Self {
+        Self(shared)
+    }
+}
+
+impl<T> Clone for SharedOwner<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Deref for SharedOwner<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+// SAFETY: the data is allocated in a collector node, which does not move when the pointer is moved.
+unsafe impl<T> StableDeref for SharedOwner<T> {}
+
+// SAFETY: cloning a Shared pointer only increments its reference count, the data is not moved.
+unsafe impl<T> CloneStableDeref for SharedOwner<T> {}
+
+impl<T: Debug> Debug for SharedOwner<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.0.deref().fmt(f)
+    }
+}
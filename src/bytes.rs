@@ -0,0 +1,146 @@
+//! Support for [`bytes::Bytes`](::bytes::Bytes) as the owned pointer of a [`Selfie`].
+//!
+//! This module is only available with the `bytes` feature enabled.
+
+#![allow(unsafe_code)] // Implementing StableDeref is inherently unsafe
+
+use crate::refs::Ref;
+use crate::Selfie;
+use ::bytes::Bytes;
+use core::fmt::{Debug, Formatter};
+use core::ops::{Deref, RangeBounds};
+use core::pin::Pin;
+use stable_deref_trait::{CloneStableDeref, StableDeref};
+
+/// A cheaply cloneable, shared byte buffer, usable as the owned pointer of a [`Selfie`].
+///
+/// This is a thin wrapper around [`Bytes`], which dereferences to its bytes. Because cloning it
+/// does not clone the bytes themselves, it can be used with [`Selfie::map_cloned`].
+///
+/// # Example
+///
+/// ```
+/// use std::pin::Pin;
+/// use bytes::Bytes;
+/// use selfie::bytes::BytesOwner;
+/// use selfie::refs::Ref;
+/// use selfie::Selfie;
+///
+/// let bytes = Bytes::from_static(b"Hello, world!");
+/// let selfie: Selfie<BytesOwner, Ref<[u8]>> = Selfie::new(Pin::new(bytes.into()), |b| &b[0..5]);
+///
+/// let second_selfie = selfie.map_cloned::<Ref<[u8]>, _>(|b, _| &b[3..]);
+/// assert_eq!(b"lo", second_selfie.with_referential(|r| *r));
+/// ```
+#[derive(Clone)]
+pub struct BytesOwner(Bytes);
+
+impl BytesOwner {
+    /// Unwraps this owner, returning the inner [`Bytes`].
+    #[inline]
+    pub fn into_inner(self) -> Bytes {
+        self.0
+    }
+}
+
+impl From<Bytes> for BytesOwner {
+    #[inline]
+    fn from(bytes: Bytes) -> Self {
+        Self(bytes)
+    }
+}
+
+impl Deref for BytesOwner {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+// SAFETY: the bytes do not move when the Bytes handle itself is moved.
+unsafe impl StableDeref for BytesOwner {}
+
+// SAFETY: cloning a Bytes handle does not clone (nor move) the bytes themselves.
+unsafe impl CloneStableDeref for BytesOwner {}
+
+impl Debug for BytesOwner {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<'a> Selfie<'a, BytesOwner, Ref<[u8]>> {
+    /// Creates a new [`Selfie`] referencing the whole given [`Bytes`] buffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bytes::Bytes;
+    /// use selfie::Selfie;
+    ///
+    /// let selfie = Selfie::from_bytes(Bytes::from_static(b"Hello, world!"));
+    /// assert_eq!(b"Hello, world!", selfie.with_referential(|r| *r));
+    /// ```
+    #[inline]
+    pub fn from_bytes(bytes: Bytes) -> Self {
+        Selfie::new(Pin::new(BytesOwner(bytes)), |b| b)
+    }
+
+    /// Returns a new [`Selfie`] referencing a sub-slice of this [`Selfie`]'s referential, sharing
+    /// the same [`Bytes`] buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds of the current referential.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bytes::Bytes;
+    /// use selfie::Selfie;
+    ///
+    /// let selfie = Selfie::from_bytes(Bytes::from_static(b"Hello, world!"));
+    /// let hello = selfie.slice(..5);
+    /// let ll = hello.slice(2..4);
+    ///
+    /// assert_eq!(b"Hello", hello.with_referential(|r| *r));
+    /// assert_eq!(b"ll", ll.with_referential(|r| *r));
+    /// ```
+    #[inline]
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        self.map_cloned(|bytes, _| &bytes[range])
+    }
+
+    /// Splits this [`Selfie`]'s referential in two at the given index, returning two new
+    /// [`Selfie`]s sharing the same [`Bytes`] buffer.
+    ///
+    /// The first one references `[0, mid)`, and the second one references `[mid, len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than the length of the current referential.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bytes::Bytes;
+    /// use selfie::Selfie;
+    ///
+    /// let selfie = Selfie::from_bytes(Bytes::from_static(b"Hello, world!"));
+    /// let (hello, world) = selfie.split_at(7);
+    ///
+    /// assert_eq!(b"Hello, ", hello.with_referential(|r| *r));
+    /// assert_eq!(b"world!", world.with_referential(|r| *r));
+    /// ```
+    #[inline]
+    pub fn split_at(&self, mid: usize) -> (Self, Self) {
+        (
+            self.map_cloned(|bytes, _| &bytes[..mid]),
+            self.map_cloned(|bytes, _| &bytes[mid..]),
+        )
+    }
+}
@@ -0,0 +1,322 @@
+//! Owner-chaining: a [`Selfie`] whose referential type owns another pointer, which is itself
+//! borrowed by an innermost referential type.
+
+use crate::refs::{Owned, RefFamily, SelfieRef};
+use crate::{Selfie, SelfieError};
+use core::fmt::{Debug, Formatter};
+use core::pin::Pin;
+use stable_deref_trait::StableDeref;
+
+/// A self-referential struct with two levels of ownership: a second owned pointer (`P2`), created
+/// from the data behind a first owned pointer (`P1`), and a shared reference (`R`) to the data
+/// behind that second pointer.
+///
+/// This is useful when a view into some data has to allocate a new buffer, which must then be
+/// borrowed from, e.g. when decompressing part of a buffer.
+///
+/// This is built on top of [`Selfie`] and [`SelfieRef`]: a [`Chain`] is a
+/// `Selfie<'a, P1, SelfieRef<Owned<P2>, R>>`, and can be converted to and from it at any time.
+///
+/// When dropped, the referential type `R` is dropped first, followed by the data behind `P2`, and
+/// finally the data behind `P1`.
+///
+/// `P2` and `R` must be `'static`, unlike `P1`. The intermediate level is a
+/// [`SelfieRef<Owned<P2>, R>`](SelfieRef) referential, which (like any [`RefFamily`]) must be valid
+/// for every self-referential lifetime. This is only possible if it does not borrow anything
+/// itself. This does not prevent `R` from borrowing from the data behind `P2`, since that
+/// borrow uses the self-referential lifetime.
+///
+/// # Example
+///
+/// ```
+/// use std::pin::Pin;
+/// use selfie::refs::Ref;
+/// use selfie::Chain;
+///
+/// let compressed: Pin<Vec<u8>> = Pin::new(vec![0, 3, b'a', 2, b'b', 0]);
+///
+/// let chain: Chain<Vec<u8>, Vec<u8>, Ref<[u8]>> = Chain::new(
+///     compressed,
+///     |compressed| {
+///         // Run-length decoding of the relevant part of the compressed data
+///         let decompressed = compressed[1..5]
+///             .chunks(2)
+///             .flat_map(|chunk| std::iter::repeat(chunk[1]).take(chunk[0] as usize))
+///             .collect::<Vec<u8>>();
+///         Pin::new(decompressed)
+///     },
+///     |decompressed| &decompressed[1..],
+/// );
+///
+/// assert_eq!(b"aabb", chain.with_referential(|r| *r));
+/// assert_eq!(b"aaabb", chain.intermediate());
+/// assert_eq!(6, chain.owned().len());
+/// ```
+pub struct Chain<'a, P1, P2, R>
+where
+    P1: 'a,
+    P2: 'static,
+    R: RefFamily + 'static,
+{
+    inner: Selfie<'a, P1, SelfieRef<Owned<P2>, R>>,
+}
+
+impl<'a, P1, P2, R> Chain<'a, P1, P2, R>
+where
+    P1: StableDeref + 'a,
+    P2: StableDeref + 'static,
+    R: RefFamily + 'static,
+    P1::Target: 'a,
+{
+    /// Creates a new [`Chain`] from a pinned pointer `P1`, a closure to create the second pinned
+    /// pointer `P2` from a shared reference to the data behind `P1`, and a closure to create the
+    /// reference type `R` from a shared reference to the data behind `P2`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::Ref;
+    /// use selfie::Chain;
+    ///
+    /// let data: Pin<String> = Pin::new("Hello, world!".to_owned());
+    /// let chain: Chain<String, String, Ref<str>> =
+    ///     Chain::new(data, |s| Pin::new(s.to_uppercase()), |s| &s[7..]);
+    ///
+    /// assert_eq!("WORLD!", chain.with_referential(|r| *r));
+    /// ```
+    pub fn new<F1, F2>(owned: Pin<P1>, make_intermediate: F1, handler: F2) -> Self
+    where
+        F1: for<'this> FnOnce(&'this P1::Target) -> Pin<P2>,
+        F2: for<'this> FnOnce(&'this P2::Target) -> <R as RefFamily>::Ref<'this>,
+    {
+        Self {
+            inner: Selfie::new(owned, |owned| {
+                Selfie::new(make_intermediate(owned), handler)
+            }),
+        }
+    }
+
+    /// Creates a new [`Chain`] from a pinned pointer `P1`, and two fallible closures to create the
+    /// second pinned pointer `P2` and the reference type `R`, respectively.
+    ///
+    /// # Errors
+    ///
+    /// If either closure returns an `Err`, it will be returned in a [`SelfieError`] alongside the
+    /// first owned pointer `P1`. The second owned pointer `P2`, if it was created, is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::Ref;
+    /// use selfie::{Chain, SelfieError};
+    ///
+    /// let data: Pin<String> = Pin::new("42".to_owned());
+    /// let chain: Result<Chain<String, String, Ref<str>>, SelfieError<String, &str>> = Chain::try_new(
+    ///     data,
+    ///     |s| Ok(Pin::new(s.repeat(2))),
+    ///     |s| s.get(1..3).ok_or("too short"),
+    /// );
+    ///
+    /// assert_eq!("24", chain.unwrap().with_referential(|r| *r));
+    /// ```
+    pub fn try_new<E, F1, F2>(
+        owned: Pin<P1>,
+        make_intermediate: F1,
+        handler: F2,
+    ) -> Result<Self, SelfieError<P1, E>>
+    where
+        F1: for<'this> FnOnce(&'this P1::Target) -> Result<Pin<P2>, E>,
+        F2: for<'this> FnOnce(&'this P2::Target) -> Result<<R as RefFamily>::Ref<'this>, E>,
+    {
+        let inner = Selfie::try_new(owned, |owned| {
+            Selfie::try_new(make_intermediate(owned)?, handler).map_err(|e| e.error)
+        })?;
+
+        Ok(Self { inner })
+    }
+
+    /// Returns a shared reference to the data behind the first owned pointer `P1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::Ref;
+    /// use selfie::Chain;
+    ///
+    /// let data: Pin<String> = Pin::new("Hello, world!".to_owned());
+    /// let chain: Chain<String, String, Ref<str>> =
+    ///     Chain::new(data, |s| Pin::new(s.to_uppercase()), |s| &s[7..]);
+    ///
+    /// assert_eq!("Hello, world!", chain.owned());
+    /// ```
+    #[inline]
+    pub fn owned(&self) -> &P1::Target {
+        self.inner.owned()
+    }
+
+    /// Returns a shared reference to the data behind the second owned pointer `P2`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::Ref;
+    /// use selfie::Chain;
+    ///
+    /// let data: Pin<String> = Pin::new("Hello, world!".to_owned());
+    /// let chain: Chain<String, String, Ref<str>> =
+    ///     Chain::new(data, |s| Pin::new(s.to_uppercase()), |s| &s[7..]);
+    ///
+    /// assert_eq!("HELLO, WORLD!", chain.intermediate());
+    /// ```
+    #[inline]
+    pub fn intermediate(&self) -> &P2::Target {
+        self.inner.with_referential(|inner| inner.owned())
+    }
+
+    /// Performs an operation borrowing the referential type `R`, and returns its result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::Ref;
+    /// use selfie::Chain;
+    ///
+    /// let data: Pin<String> = Pin::new("Hello, world!".to_owned());
+    /// let chain: Chain<String, String, Ref<str>> =
+    ///     Chain::new(data, |s| Pin::new(s.to_uppercase()), |s| &s[7..]);
+    ///
+    /// assert_eq!(6, chain.with_referential(|r| r.len()));
+    /// ```
+    #[inline]
+    pub fn with_referential<'s, F, T>(&'s self, handler: F) -> T
+    where
+        F: for<'this> FnOnce(&'s <R as RefFamily>::Ref<'this>) -> T,
+    {
+        self.inner
+            .with_referential(|inner| inner.with_referential(handler))
+    }
+
+    /// Performs an operation mutably borrowing the referential type `R`, and returns its result.
+    ///
+    /// Note that this operation *cannot* mutably access the data behind `P1` nor `P2`, it only
+    /// mutates the referential type `R` itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::Ref;
+    /// use selfie::Chain;
+    ///
+    /// let data: Pin<String> = Pin::new("Hello, world!".to_owned());
+    /// let mut chain: Chain<String, String, Ref<str>> =
+    ///     Chain::new(data, |s| Pin::new(s.to_uppercase()), |s| &s[7..]);
+    ///
+    /// chain.with_referential_mut(|r| *r = &r[..5]);
+    /// assert_eq!("WORLD", chain.with_referential(|r| *r));
+    /// ```
+    #[inline]
+    pub fn with_referential_mut<'s, F, T>(&'s mut self, handler: F) -> T
+    where
+        F: for<'this> FnOnce(&'s mut <R as RefFamily>::Ref<'this>) -> T,
+    {
+        self.inner
+            .with_referential_mut(|inner| inner.with_referential_mut(handler))
+    }
+
+    /// Unwraps the [`Chain`] by dropping the reference type `R` and the second owned pointer
+    /// `P2`, and returning the first owned pointer `P1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::Ref;
+    /// use selfie::Chain;
+    ///
+    /// let data: Pin<String> = Pin::new("Hello, world!".to_owned());
+    /// let chain: Chain<String, String, Ref<str>> =
+    ///     Chain::new(data, |s| Pin::new(s.to_uppercase()), |s| &s[7..]);
+    ///
+    /// # // Miri reports dropping P2 while self is passed by value as UB, see tests/chain.rs
+    /// # if cfg!(miri) { return; }
+    /// let data: Pin<String> = chain.into_owned();
+    /// assert_eq!("Hello, world!", &*data);
+    /// ```
+    #[inline]
+    pub fn into_owned(self) -> Pin<P1> {
+        self.inner.into_owned()
+    }
+
+    /// Returns the [`Selfie`] this [`Chain`] is built on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::Ref;
+    /// use selfie::Chain;
+    ///
+    /// let data: Pin<String> = Pin::new("Hello, world!".to_owned());
+    /// let chain: Chain<String, String, Ref<str>> =
+    ///     Chain::new(data, |s| Pin::new(s.to_uppercase()), |s| &s[7..]);
+    ///
+    /// let selfie = chain.into_inner();
+    /// assert_eq!("WORLD!", selfie.with_referential(|inner| inner.with_referential(|r| *r)));
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> Selfie<'a, P1, SelfieRef<Owned<P2>, R>> {
+        self.inner
+    }
+}
+
+impl<'a, P1, P2, R> From<Selfie<'a, P1, SelfieRef<Owned<P2>, R>>> for Chain<'a, P1, P2, R>
+where
+    P1: StableDeref + 'a,
+    P2: StableDeref + 'static,
+    R: RefFamily + 'static,
+    P1::Target: 'a,
+{
+    #[inline]
+    fn from(inner: Selfie<'a, P1, SelfieRef<Owned<P2>, R>>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<'a, P1, P2, R> From<Chain<'a, P1, P2, R>> for Selfie<'a, P1, SelfieRef<Owned<P2>, R>>
+where
+    P1: StableDeref + 'a,
+    P2: StableDeref + 'static,
+    R: RefFamily + 'static,
+    P1::Target: 'a,
+{
+    #[inline]
+    fn from(chain: Chain<'a, P1, P2, R>) -> Self {
+        chain.into_inner()
+    }
+}
+
+impl<'a, P1, P2, R> Debug for Chain<'a, P1, P2, R>
+where
+    P1::Target: Debug,
+    P2::Target: Debug,
+    for<'this> <R as RefFamily>::Ref<'this>: Debug,
+    P1: StableDeref + 'a,
+    P2: StableDeref + 'static,
+    R: RefFamily + 'static,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.with_referential(|referential| {
+            f.debug_struct("Chain")
+                .field("owned", &self.owned())
+                .field("intermediate", &self.intermediate())
+                .field("referential", referential)
+                .finish()
+        })
+    }
+}
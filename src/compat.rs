@@ -0,0 +1,272 @@
+//! A [`self_cell`](https://crates.io/crates/self_cell)-like API on top of [`Selfie`], to ease
+//! migrating between `Selfie` and other self-referential struct libraries.
+//!
+//! The [`SelfCell`] type mirrors the methods generated by `self_cell`'s macro (and their
+//! `ouroboros` counterparts), and can be converted to and from a [`Selfie`] at any time:
+//!
+//! | `self_cell`            | `ouroboros`            | [`SelfCell`]                                 |
+//! |------------------------|------------------------|----------------------------------------------|
+//! | `new`                  | `new`                  | [`new`](SelfCell::new)                       |
+//! | `try_new`              | `try_new`              | [`try_new`](SelfCell::try_new)               |
+//! | `try_new_or_recover`   | `try_new_or_recover`   | [`try_new_or_recover`](SelfCell::try_new_or_recover) |
+//! | `borrow_owner`         | `borrow_<owner>`       | [`borrow_owner`](SelfCell::borrow_owner)     |
+//! | `borrow_dependent`     | `borrow_<dependent>`   | [`borrow_dependent`](SelfCell::borrow_dependent) |
+//! | `with_dependent`       | `with`                 | [`with_dependent`](SelfCell::with_dependent) |
+//! | `with_dependent_mut`   | `with_mut`             | [`with_dependent_mut`](SelfCell::with_dependent_mut) |
+//! | `into_owner`           | `into_heads`           | [`into_owner`](SelfCell::into_owner)         |
+//!
+//! Unlike `self_cell`, the owner is not boxed: it has to be a [`StableDeref`] pointer, and the
+//! dependent borrows the data behind it instead of the pointer itself.
+//!
+//! # Example
+//!
+//! ```
+//! use selfie::compat::SelfCell;
+//! use selfie::refs::Ref;
+//! use selfie::Selfie;
+//!
+//! let cell: SelfCell<String, Ref<str>> = SelfCell::new("Hello, world!".to_owned(), |s| &s[0..5]);
+//!
+//! assert_eq!("Hello, world!", cell.borrow_owner());
+//! assert_eq!("Hello", *cell.borrow_dependent());
+//!
+//! // Switching to the Selfie API, and back
+//! let selfie: Selfie<String, Ref<str>> = cell.into();
+//! assert_eq!("Hello", selfie.with_referential(|r| *r));
+//!
+//! let cell: SelfCell<String, Ref<str>> = selfie.into();
+//! assert_eq!("Hello, world!", cell.into_owner());
+//! ```
+
+use crate::refs::{Covariant, RefFamily};
+use crate::{Selfie, SelfieError};
+use core::pin::Pin;
+use stable_deref_trait::StableDeref;
+
+/// A self-referential struct with a `self_cell`-like API, implemented on top of [`Selfie`].
+///
+/// See the [module documentation](self) for more information.
+pub struct SelfCell<'a, P, R>
+where
+    P: 'a,
+    R: RefFamily,
+{
+    inner: Selfie<'a, P, R>,
+}
+
+impl<'a, P, R> SelfCell<'a, P, R>
+where
+    P: StableDeref + 'a,
+    P::Target: Unpin + 'a,
+    R: RefFamily,
+{
+    /// Creates a new [`SelfCell`] from an owner `P`, and a closure to create the dependent type
+    /// `R` from a shared reference to the data behind `P`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use selfie::compat::SelfCell;
+    /// use selfie::refs::Ref;
+    ///
+    /// let cell: SelfCell<String, Ref<str>> = SelfCell::new("Hello, world!".to_owned(), |s| &s[0..5]);
+    /// assert_eq!("Hello", *cell.borrow_dependent());
+    /// ```
+    #[inline]
+    pub fn new<F>(owner: P, dependent_builder: F) -> Self
+    where
+        F: for<'this> FnOnce(&'this P::Target) -> <R as RefFamily>::Ref<'this>,
+    {
+        Self {
+            inner: Selfie::new(Pin::new(owner), dependent_builder),
+        }
+    }
+
+    /// Creates a new [`SelfCell`] from an owner `P`, and a fallible closure to create the dependent
+    /// type `R` from a shared reference to the data behind `P`.
+    ///
+    /// # Errors
+    ///
+    /// If the closure returns an `Err`, it is returned as-is, and the owner is dropped. See
+    /// [`try_new_or_recover`](SelfCell::try_new_or_recover) to also recover the owner.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use selfie::compat::SelfCell;
+    /// use selfie::refs::Ref;
+    ///
+    /// let cell: Result<SelfCell<String, Ref<str>>, ()> =
+    ///     SelfCell::try_new("Hello".to_owned(), |s| s.get(0..10).ok_or(()));
+    /// assert!(cell.is_err());
+    /// ```
+    #[inline]
+    pub fn try_new<E, F>(owner: P, dependent_builder: F) -> Result<Self, E>
+    where
+        F: for<'this> FnOnce(&'this P::Target) -> Result<<R as RefFamily>::Ref<'this>, E>,
+    {
+        match Selfie::try_new(Pin::new(owner), dependent_builder) {
+            Ok(inner) => Ok(Self { inner }),
+            Err(SelfieError { error, .. }) => Err(error),
+        }
+    }
+
+    /// Creates a new [`SelfCell`] from an owner `P`, and a fallible closure to create the dependent
+    /// type `R` from a shared reference to the data behind `P`.
+    ///
+    /// # Errors
+    ///
+    /// If the closure returns an `Err`, it is returned alongside the original owner.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use selfie::compat::SelfCell;
+    /// use selfie::refs::Ref;
+    ///
+    /// let cell: Result<SelfCell<String, Ref<str>>, (String, ())> =
+    ///     SelfCell::try_new_or_recover("Hello".to_owned(), |s| s.get(0..10).ok_or(()));
+    /// assert_eq!(("Hello".to_owned(), ()), cell.err().unwrap());
+    /// ```
+    #[inline]
+    pub fn try_new_or_recover<E, F>(owner: P, dependent_builder: F) -> Result<Self, (P, E)>
+    where
+        F: for<'this> FnOnce(&'this P::Target) -> Result<<R as RefFamily>::Ref<'this>, E>,
+    {
+        match Selfie::try_new(Pin::new(owner), dependent_builder) {
+            Ok(inner) => Ok(Self { inner }),
+            Err(SelfieError { owned, error }) => Err((Pin::into_inner(owned), error)),
+        }
+    }
+
+    /// Unwraps the [`SelfCell`] by dropping the dependent type `R`, and returning the owner `P`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use selfie::compat::SelfCell;
+    /// use selfie::refs::Ref;
+    ///
+    /// let cell: SelfCell<String, Ref<str>> = SelfCell::new("Hello, world!".to_owned(), |s| &s[0..5]);
+    /// assert_eq!("Hello, world!", cell.into_owner());
+    /// ```
+    #[inline]
+    pub fn into_owner(self) -> P {
+        Pin::into_inner(self.inner.into_owned())
+    }
+}
+
+impl<'a, P, R> SelfCell<'a, P, R>
+where
+    P: StableDeref + 'a,
+    P::Target: 'a,
+    R: RefFamily,
+{
+    /// Returns a shared reference to the data behind the owner `P`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use selfie::compat::SelfCell;
+    /// use selfie::refs::Ref;
+    ///
+    /// let cell: SelfCell<String, Ref<str>> = SelfCell::new("Hello, world!".to_owned(), |s| &s[0..5]);
+    /// assert_eq!("Hello, world!", cell.borrow_owner());
+    /// ```
+    #[inline]
+    pub fn borrow_owner(&self) -> &P::Target {
+        self.inner.owned()
+    }
+
+    /// Returns a shared reference to the dependent type `R`.
+    ///
+    /// This is only available for [`Covariant`] dependent types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use selfie::compat::SelfCell;
+    /// use selfie::refs::Ref;
+    ///
+    /// let cell: SelfCell<String, Ref<str>> = SelfCell::new("Hello, world!".to_owned(), |s| &s[0..5]);
+    /// assert_eq!("Hello", *cell.borrow_dependent());
+    /// ```
+    #[inline]
+    pub fn borrow_dependent<'s>(&'s self) -> &'s <R as RefFamily>::Ref<'s>
+    where
+        R: Covariant,
+    {
+        self.inner.get()
+    }
+
+    /// Performs an operation borrowing both the data behind the owner `P` and the dependent type
+    /// `R`, and returns its result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use selfie::compat::SelfCell;
+    /// use selfie::refs::Ref;
+    ///
+    /// let cell: SelfCell<String, Ref<str>> = SelfCell::new("Hello, world!".to_owned(), |s| &s[0..5]);
+    /// assert_eq!(8, cell.with_dependent(|owner, dependent| owner.len() - dependent.len()));
+    /// ```
+    #[inline]
+    pub fn with_dependent<'s, F, T>(&'s self, handler: F) -> T
+    where
+        F: for<'this> FnOnce(&'s P::Target, &'s <R as RefFamily>::Ref<'this>) -> T,
+    {
+        let owner = self.inner.owned();
+        self.inner
+            .with_referential(|dependent| handler(owner, dependent))
+    }
+
+    /// Performs an operation borrowing the data behind the owner `P` and mutably borrowing the
+    /// dependent type `R`, and returns its result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use selfie::compat::SelfCell;
+    /// use selfie::refs::Ref;
+    ///
+    /// let mut cell: SelfCell<String, Ref<str>> =
+    ///     SelfCell::new("Hello, world!".to_owned(), |s| &s[0..5]);
+    ///
+    /// cell.with_dependent_mut(|owner, dependent| {
+    ///     assert_eq!("Hello, world!", owner);
+    ///     *dependent = &dependent[3..];
+    /// });
+    ///
+    /// assert_eq!("lo", *cell.borrow_dependent());
+    /// ```
+    #[inline]
+    pub fn with_dependent_mut<'s, F, T>(&'s mut self, handler: F) -> T
+    where
+        F: for<'this> FnOnce(&'s P::Target, &'s mut <R as RefFamily>::Ref<'this>) -> T,
+    {
+        self.inner.with_owned_and_referential_mut(handler)
+    }
+}
+
+impl<'a, P, R> From<Selfie<'a, P, R>> for SelfCell<'a, P, R>
+where
+    P: StableDeref + 'a,
+    R: RefFamily,
+{
+    #[inline]
+    fn from(inner: Selfie<'a, P, R>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<'a, P, R> From<SelfCell<'a, P, R>> for Selfie<'a, P, R>
+where
+    P: StableDeref + 'a,
+    R: RefFamily,
+{
+    #[inline]
+    fn from(cell: SelfCell<'a, P, R>) -> Self {
+        cell.inner
+    }
+}
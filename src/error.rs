@@ -1,10 +1,14 @@
 use core::fmt::{Debug, Display, Formatter};
+use core::ops::Deref;
 use core::pin::Pin;
 
 /// An error wrapper containing both an error and an owned value.
 ///
 /// This is used by methods such as [`Selfie::try_new`](crate::Selfie::try_new) to allow recovering the owned pointer if
 /// its reference handler failed.
+///
+/// This implements the [`Error`](core::error::Error) trait if the error value does. This is
+/// available without the `std` feature when using Rust 1.81 or newer.
 pub struct SelfieError<P, E> {
     /// The owned value.
     pub owned: Pin<P>,
@@ -12,6 +16,97 @@ pub struct SelfieError<P, E> {
     pub error: E,
 }
 
+impl<P, E> SelfieError<P, E> {
+    /// Unwraps this error, returning both the owned pointer and the error value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::Ref;
+    /// use selfie::{Selfie, SelfieError};
+    ///
+    /// let data = Pin::new("Hello, world!".to_owned());
+    /// let error: SelfieError<String, &str> =
+    ///     Selfie::<String, Ref<str>>::try_new(data, |_| Err("Oops")).unwrap_err();
+    ///
+    /// let (owned, error) = error.into_parts();
+    /// assert_eq!("Hello, world!", &*owned);
+    /// assert_eq!("Oops", error);
+    /// ```
+    #[inline]
+    pub fn into_parts(self) -> (Pin<P>, E) {
+        (self.owned, self.error)
+    }
+
+    /// Unwraps this error, returning the owned pointer and dropping the error value.
+    #[inline]
+    pub fn into_owned(self) -> Pin<P> {
+        self.owned
+    }
+
+    /// Unwraps this error, returning the error value and dropping the owned pointer.
+    #[inline]
+    pub fn into_error(self) -> E {
+        self.error
+    }
+
+    /// Maps the error value using the given closure, keeping the owned pointer unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::Ref;
+    /// use selfie::{Selfie, SelfieError};
+    ///
+    /// let data = Pin::new("Hello, world!".to_owned());
+    /// let error: SelfieError<String, &str> =
+    ///     Selfie::<String, Ref<str>>::try_new(data, |_| Err("Oops")).unwrap_err();
+    ///
+    /// let error: SelfieError<String, usize> = error.map_err(|e| e.len());
+    /// assert_eq!(4, error.error);
+    /// assert_eq!("Hello, world!", &*error.owned);
+    /// ```
+    #[inline]
+    pub fn map_err<E2, F: FnOnce(E) -> E2>(self, mapper: F) -> SelfieError<P, E2> {
+        SelfieError {
+            owned: self.owned,
+            error: mapper(self.error),
+        }
+    }
+}
+
+impl<P: Clone, E: Clone> Clone for SelfieError<P, E> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            owned: self.owned.clone(),
+            error: self.error.clone(),
+        }
+    }
+}
+
+impl<P, E> PartialEq for SelfieError<P, E>
+where
+    P: Deref,
+    P::Target: PartialEq,
+    E: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.owned == other.owned && self.error == other.error
+    }
+}
+
+impl<P, E> Eq for SelfieError<P, E>
+where
+    P: Deref,
+    P::Target: Eq,
+    E: Eq,
+{
+}
+
 impl<P, E: Debug> Debug for SelfieError<P, E> {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
@@ -26,16 +121,39 @@ impl<P, E: Display> Display for SelfieError<P, E> {
     }
 }
 
+// core::error::Error is only available in newer Rust versions, see build.rs
+#[cfg(selfie_core_error)]
+mod core_impl {
+    use crate::SelfieError;
+    use core::error::Error;
+
+    impl<P, E: Error + 'static> Error for SelfieError<P, E> {
+        #[inline]
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.error)
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 mod std_impl {
     extern crate std;
     use crate::SelfieError;
-    use std::error::Error;
+    use std::io;
 
-    impl<P, E: Error + 'static> Error for SelfieError<P, E> {
+    // On newer Rust versions, this is the same trait as core::error::Error
+    #[cfg(not(selfie_core_error))]
+    impl<P, E: std::error::Error + 'static> std::error::Error for SelfieError<P, E> {
         #[inline]
-        fn source(&self) -> Option<&(dyn Error + 'static)> {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
             Some(&self.error)
         }
     }
+
+    impl<P, E: Into<io::Error>> From<SelfieError<P, E>> for io::Error {
+        #[inline]
+        fn from(error: SelfieError<P, E>) -> Self {
+            error.error.into()
+        }
+    }
 }
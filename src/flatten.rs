@@ -0,0 +1,240 @@
+//! Helpers to work with cascaded [`Selfie`]s, i.e. [`Selfie`]s holding other [`Selfie`]s as their
+//! referential type.
+
+use crate::refs::{Flatten, RefFamily, Then};
+use crate::Selfie;
+use core::fmt::{Debug, Formatter};
+use core::pin::Pin;
+use stable_deref_trait::StableDeref;
+
+impl<'a, P, R> Selfie<'a, P, R>
+where
+    P: StableDeref + 'a,
+    R: RefFamily,
+    P::Target: 'a,
+{
+    /// Performs an operation borrowing the innermost referential type of cascaded [`Selfie`]s, and
+    /// returns its result.
+    ///
+    /// This is equivalent to nesting a [`with_referential`](Selfie::with_referential) call for
+    /// each level of [`SelfieRef`](crate::refs::SelfieRef).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::{Ref, SelfieRef};
+    /// use selfie::Selfie;
+    ///
+    /// let data = Pin::new("Hello, world!".to_owned());
+    /// let selfie: Selfie<String, SelfieRef<Ref<str>, SelfieRef<Ref<str>, Ref<str>>>> =
+    ///     Selfie::new(data, |s| {
+    ///         Selfie::new(Pin::new(&s[0..5]), |s| Selfie::new(Pin::new(&s[1..]), |s| &s[2..]))
+    ///     });
+    ///
+    /// assert_eq!("lo", selfie.with_innermost(|r| *r));
+    /// ```
+    #[inline]
+    pub fn with_innermost<'s, F, T>(&'s self, handler: F) -> T
+    where
+        R: Flatten,
+        F: for<'this> FnOnce(&'s <R::Innermost as RefFamily>::Ref<'this>) -> T,
+    {
+        self.with_referential(|referential| R::with_innermost(referential, handler))
+    }
+
+    /// Wraps these cascaded [`Selfie`]s into a [`FlatSelfie`], which directly exposes their
+    /// innermost referential type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::{Ref, SelfieRef};
+    /// use selfie::Selfie;
+    ///
+    /// let data = Pin::new("Hello, world!".to_owned());
+    /// let selfie: Selfie<String, SelfieRef<Ref<str>, Ref<str>>> =
+    ///     Selfie::new(data, |s| Selfie::new(Pin::new(&s[0..5]), |s| &s[3..]));
+    ///
+    /// let flat = selfie.flatten();
+    /// assert_eq!("lo", flat.with_referential(|r| *r));
+    /// assert_eq!("Hello, world!", flat.owned());
+    /// ```
+    #[inline]
+    pub fn flatten(self) -> FlatSelfie<'a, P, R>
+    where
+        R: Flatten,
+    {
+        FlatSelfie { inner: self }
+    }
+
+    /// Creates cascaded [`Selfie`]s by adding a new innermost level, which borrows from the data
+    /// referenced by the current innermost reference.
+    ///
+    /// This allows to build cascaded [`Selfie`]s one level at a time, instead of nesting
+    /// [`Selfie::new`] calls.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::{Ref, SelfieRef};
+    /// use selfie::Selfie;
+    ///
+    /// let data = Pin::new("Hello, world!".to_owned());
+    /// let selfie: Selfie<String, Ref<str>> = Selfie::new(data, |s| &s[0..5]);
+    ///
+    /// let selfie: Selfie<String, SelfieRef<Ref<str>, SelfieRef<Ref<str>, Ref<str>>>> = selfie
+    ///     .then::<Ref<str>, _>(|s| &s[1..])
+    ///     .then::<Ref<str>, _>(|s| &s[2..]);
+    ///
+    /// assert_eq!("lo", selfie.with_innermost(|r| *r));
+    /// ```
+    #[inline]
+    pub fn then<R2, F>(self, handler: F) -> Selfie<'a, P, R::Output>
+    where
+        R: Then<R2>,
+        R2: RefFamily,
+        F: for<'this> FnOnce(&'this R::Target) -> <R2 as RefFamily>::Ref<'this>,
+    {
+        self.map(|referential, _| R::then(referential, handler))
+    }
+}
+
+/// Cascaded [`Selfie`]s, which directly expose their innermost referential type.
+///
+/// A [`FlatSelfie`] is created using [`Selfie::flatten`].
+pub struct FlatSelfie<'a, P, R>
+where
+    P: 'a,
+    R: RefFamily,
+{
+    inner: Selfie<'a, P, R>,
+}
+
+impl<'a, P, R> FlatSelfie<'a, P, R>
+where
+    P: StableDeref + 'a,
+    R: Flatten,
+    P::Target: 'a,
+{
+    /// Returns a shared reference to the data behind the outermost owned pointer `P`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::{Ref, SelfieRef};
+    /// use selfie::Selfie;
+    ///
+    /// let data = Pin::new("Hello, world!".to_owned());
+    /// let selfie: Selfie<String, SelfieRef<Ref<str>, Ref<str>>> =
+    ///     Selfie::new(data, |s| Selfie::new(Pin::new(&s[0..5]), |s| &s[3..]));
+    ///
+    /// let flat = selfie.flatten();
+    /// assert_eq!("Hello, world!", flat.owned());
+    /// ```
+    #[inline]
+    pub fn owned(&self) -> &P::Target {
+        self.inner.owned()
+    }
+
+    /// Performs an operation borrowing the innermost referential type, and returns its result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::{Ref, SelfieRef};
+    /// use selfie::Selfie;
+    ///
+    /// let data = Pin::new("Hello, world!".to_owned());
+    /// let selfie: Selfie<String, SelfieRef<Ref<str>, Ref<str>>> =
+    ///     Selfie::new(data, |s| Selfie::new(Pin::new(&s[0..5]), |s| &s[3..]));
+    ///
+    /// let flat = selfie.flatten();
+    /// assert_eq!(2, flat.with_referential(|r| r.len()));
+    /// ```
+    #[inline]
+    pub fn with_referential<'s, F, T>(&'s self, handler: F) -> T
+    where
+        F: for<'this> FnOnce(&'s <R::Innermost as RefFamily>::Ref<'this>) -> T,
+    {
+        self.inner.with_innermost(handler)
+    }
+
+    /// Unwraps the cascaded [`Selfie`]s by dropping all referential types, and returning the
+    /// outermost owned pointer `P`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::{Ref, SelfieRef};
+    /// use selfie::Selfie;
+    ///
+    /// let data = Pin::new("Hello, world!".to_owned());
+    /// let selfie: Selfie<String, SelfieRef<Ref<str>, Ref<str>>> =
+    ///     Selfie::new(data, |s| Selfie::new(Pin::new(&s[0..5]), |s| &s[3..]));
+    ///
+    /// let flat = selfie.flatten();
+    /// let data: Pin<String> = flat.into_owned();
+    /// assert_eq!("Hello, world!", &*data);
+    /// ```
+    #[inline]
+    pub fn into_owned(self) -> Pin<P> {
+        self.inner.into_owned()
+    }
+
+    /// Returns the cascaded [`Selfie`]s this [`FlatSelfie`] wraps.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::{Ref, SelfieRef};
+    /// use selfie::Selfie;
+    ///
+    /// let data = Pin::new("Hello, world!".to_owned());
+    /// let selfie: Selfie<String, SelfieRef<Ref<str>, Ref<str>>> =
+    ///     Selfie::new(data, |s| Selfie::new(Pin::new(&s[0..5]), |s| &s[3..]));
+    ///
+    /// let flat = selfie.flatten();
+    /// let selfie: Selfie<String, SelfieRef<Ref<str>, Ref<str>>> = flat.into_inner();
+    /// selfie.with_referential(|r| assert_eq!("Hello", r.owned()));
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> Selfie<'a, P, R> {
+        self.inner
+    }
+}
+
+impl<'a, P, R> From<FlatSelfie<'a, P, R>> for Selfie<'a, P, R>
+where
+    P: StableDeref + 'a,
+    R: Flatten,
+    P::Target: 'a,
+{
+    #[inline]
+    fn from(flat: FlatSelfie<'a, P, R>) -> Self {
+        flat.into_inner()
+    }
+}
+
+impl<'a, P, R> Debug for FlatSelfie<'a, P, R>
+where
+    P::Target: Debug,
+    for<'this> <R::Innermost as RefFamily>::Ref<'this>: Debug,
+    P: StableDeref + 'a,
+    R: Flatten,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.with_referential(|referential| {
+            f.debug_struct("FlatSelfie")
+                .field("owned", &self.owned())
+                .field("referential", referential)
+                .finish()
+        })
+    }
+}
@@ -0,0 +1,37 @@
+//! Support for asynchronously creating [`Selfie`](crate::Selfie)s.
+//!
+//! See [`Selfie::new_async`](crate::Selfie::new_async) and
+//! [`Selfie::try_new_async`](crate::Selfie::try_new_async).
+
+use core::future::Future;
+
+/// An asynchronous closure, creating a future that borrows its input `&'this T` and resolves to
+/// `Output`.
+///
+/// This trait is automatically implemented for all functions and closures that take a `&'this T`
+/// reference and return a [`Future`] resolving to `Output`, such as `async fn`s.
+///
+/// Because the returned future borrows its input, functions that are generic over the `'this`
+/// lifetime (such as `async fn`s) have to be used instead of closures, in the same way as
+/// [`Selfie::new_async`](crate::Selfie::new_async) expects.
+pub trait AsyncHandler<'this, T: ?Sized + 'this, Output> {
+    /// The future returned by this handler.
+    type Future: Future<Output = Output> + 'this;
+
+    /// Calls this handler, returning its future.
+    fn call(self, input: &'this T) -> Self::Future;
+}
+
+impl<'this, T, F, Fut, Output> AsyncHandler<'this, T, Output> for F
+where
+    T: ?Sized + 'this,
+    F: FnOnce(&'this T) -> Fut,
+    Fut: Future<Output = Output> + 'this,
+{
+    type Future = Fut;
+
+    #[inline]
+    fn call(self, input: &'this T) -> Fut {
+        self(input)
+    }
+}
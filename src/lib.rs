@@ -5,6 +5,27 @@
 #![deny(missing_docs)]
 
 pub mod refs;
+
+pub mod future;
+
+#[cfg(feature = "alloc-guard")]
+pub mod alloc_guard;
+
+#[cfg(feature = "compat")]
+pub mod compat;
+
+#[cfg(feature = "memmap2")]
+pub mod mmap;
+
+#[cfg(feature = "bytes")]
+pub mod bytes;
+
+#[cfg(feature = "basedrop")]
+pub mod basedrop;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod rt;
+
 pub(crate) mod utils;
 
 mod error;
@@ -14,3 +35,22 @@ mod selfie;
 pub use crate::selfie::*;
 
 mod safe;
+
+mod chain;
+pub use chain::Chain;
+
+mod flatten;
+pub use flatten::FlatSelfie;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod rc;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use rc::*;
+
+#[cfg(feature = "std")]
+mod shared;
+#[cfg(feature = "std")]
+pub use shared::*;
+
+#[cfg(feature = "yoke")]
+mod yoke_impl;
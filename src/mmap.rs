@@ -0,0 +1,245 @@
+//! Memory-mapped file owners, to be used as the owned pointer of a [`Selfie`] or [`SelfieMut`](crate::SelfieMut).
+//!
+//! This module is only available with the `memmap2` feature enabled, which requires Rust 1.65 or
+//! newer.
+
+#![allow(unsafe_code)] // Mapping files and implementing StableDeref are inherently unsafe
+
+extern crate std;
+
+use crate::refs::RefFamily;
+use crate::{Selfie, SelfieError};
+use core::fmt::{Debug, Display, Formatter};
+use core::ops::{Deref, DerefMut};
+use core::pin::Pin;
+use memmap2::{Mmap, MmapMut};
+use stable_deref_trait::StableDeref;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+/// A read-only memory-mapped file, usable as the owned pointer of a [`Selfie`].
+///
+/// This is a thin wrapper around [`memmap2::Mmap`], which dereferences to the mapped bytes.
+///
+/// # Example
+///
+/// ```
+/// # // Miri's isolation does not allow opening files
+/// # if cfg!(miri) { return; }
+/// # let path = std::env::temp_dir().join(format!("selfie-mmap-doc-{}", std::process::id()));
+/// use std::fs::File;
+/// use std::pin::Pin;
+/// use memmap2::Mmap;
+/// use selfie::mmap::MmapOwner;
+/// use selfie::refs::Ref;
+/// use selfie::Selfie;
+///
+/// std::fs::write(&path, "Hello, world!").unwrap();
+/// let file = File::open(&path).unwrap();
+///
+/// // SAFETY: the file is not modified while it is mapped.
+/// let mmap = unsafe { Mmap::map(&file) }.unwrap();
+/// let selfie: Selfie<MmapOwner, Ref<[u8]>> = Selfie::new(Pin::new(mmap.into()), |b| &b[0..5]);
+///
+/// assert_eq!(b"Hello", selfie.with_referential(|r| *r));
+/// # drop(selfie);
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+pub struct MmapOwner(Mmap);
+
+impl MmapOwner {
+    /// Unwraps this owner, returning the inner [`Mmap`].
+    #[inline]
+    pub fn into_inner(self) -> Mmap {
+        self.0
+    }
+}
+
+impl From<Mmap> for MmapOwner {
+    #[inline]
+    fn from(mmap: Mmap) -> Self {
+        Self(mmap)
+    }
+}
+
+impl Deref for MmapOwner {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+// SAFETY: the mapped memory does not move when the Mmap itself is moved.
+unsafe impl StableDeref for MmapOwner {}
+
+impl Debug for MmapOwner {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// A writable memory-mapped file, usable as the owned pointer of a [`SelfieMut`](crate::SelfieMut).
+///
+/// This is a thin wrapper around [`memmap2::MmapMut`], which dereferences to the mapped bytes.
+///
+/// # Example
+///
+/// ```
+/// use std::pin::Pin;
+/// use memmap2::MmapMut;
+/// use selfie::mmap::MmapMutOwner;
+/// use selfie::refs::Mut;
+/// use selfie::SelfieMut;
+///
+/// let mmap = MmapMut::map_anon(5).unwrap();
+/// let mut selfie: SelfieMut<MmapMutOwner, Mut<[u8]>> =
+///     SelfieMut::new(Pin::new(mmap.into()), |b| &mut Pin::into_inner(b)[1..]);
+///
+/// selfie.with_referential_mut(|b| b.fill(42));
+/// assert_eq!([0, 42, 42, 42, 42], *selfie.into_owned());
+/// ```
+pub struct MmapMutOwner(MmapMut);
+
+impl MmapMutOwner {
+    /// Unwraps this owner, returning the inner [`MmapMut`].
+    #[inline]
+    pub fn into_inner(self) -> MmapMut {
+        self.0
+    }
+}
+
+impl From<MmapMut> for MmapMutOwner {
+    #[inline]
+    fn from(mmap: MmapMut) -> Self {
+        Self(mmap)
+    }
+}
+
+impl Deref for MmapMutOwner {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl DerefMut for MmapMutOwner {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+// SAFETY: the mapped memory does not move when the MmapMut itself is moved.
+unsafe impl StableDeref for MmapMutOwner {}
+
+impl Debug for MmapMutOwner {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// An error returned by [`Selfie::map_file`].
+pub enum MapFileError<E> {
+    /// The file could not be opened or mapped.
+    Io(io::Error),
+    /// The handler failed. The mapped file is returned alongside the handler's error.
+    Handler(SelfieError<MmapOwner, E>),
+}
+
+impl<E: Debug> Debug for MapFileError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MapFileError::Io(e) => f.debug_tuple("Io").field(e).finish(),
+            MapFileError::Handler(e) => f.debug_tuple("Handler").field(e).finish(),
+        }
+    }
+}
+
+impl<E: Display> Display for MapFileError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MapFileError::Io(e) => Display::fmt(e, f),
+            MapFileError::Handler(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for MapFileError<E> {
+    #[inline]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MapFileError::Io(e) => Some(e),
+            MapFileError::Handler(e) => Some(&e.error),
+        }
+    }
+}
+
+impl<E> From<io::Error> for MapFileError<E> {
+    #[inline]
+    fn from(error: io::Error) -> Self {
+        MapFileError::Io(error)
+    }
+}
+
+impl<'a, R> Selfie<'a, MmapOwner, R>
+where
+    R: RefFamily,
+{
+    /// Maps the file at the given path into memory, and creates a new [`Selfie`] from it using a
+    /// fallible closure to create the reference type `R` from the mapped bytes.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be opened or mapped, a [`MapFileError::Io`] is returned.
+    ///
+    /// If the closure returns an `Err`, it is returned in a [`MapFileError::Handler`], alongside
+    /// the mapped file.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified, truncated or otherwise changed (in-process or by another
+    /// process) for as long as it is mapped, i.e. until both the [`Selfie`] and its
+    /// [`MmapOwner`] are dropped. See [`Mmap::map`] for more information.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # // Miri's isolation does not allow opening files
+    /// # if cfg!(miri) { return; }
+    /// # let path = std::env::temp_dir().join(format!("selfie-map-file-doc-{}", std::process::id()));
+    /// use selfie::refs::Ref;
+    /// use selfie::Selfie;
+    ///
+    /// std::fs::write(&path, "Hello, world!").unwrap();
+    ///
+    /// // SAFETY: the file is not modified while it is mapped.
+    /// let selfie: Selfie<_, Ref<str>> =
+    ///     unsafe { Selfie::map_file(&path, |bytes| std::str::from_utf8(&bytes[0..5])) }.unwrap();
+    ///
+    /// assert_eq!("Hello", selfie.with_referential(|r| *r));
+    /// # drop(selfie);
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub unsafe fn map_file<E, F>(
+        path: impl AsRef<Path>,
+        handler: F,
+    ) -> Result<Self, MapFileError<E>>
+    where
+        F: for<'this> FnOnce(&'this [u8]) -> Result<<R as RefFamily>::Ref<'this>, E>,
+    {
+        let file = File::open(path)?;
+        // SAFETY: upheld by the caller
+        let mmap = Mmap::map(&file)?;
+
+        Selfie::try_new(Pin::new(MmapOwner(mmap)), handler).map_err(MapFileError::Handler)
+    }
+}
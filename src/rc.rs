@@ -0,0 +1,240 @@
+//! Reference-counted [`Selfie`] handles, sharing the same owned pointer and referential.
+
+extern crate alloc;
+
+use crate::refs::RefFamily;
+use crate::Selfie;
+use alloc::rc::{Rc, Weak as RcWeak};
+use alloc::sync::{Arc, Weak as ArcWeak};
+use core::fmt::{Debug, Formatter};
+use core::ops::Deref;
+
+macro_rules! reference_counted_selfie {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $weak_name:ident, $rc:ident, $weak:ident, $rc_name:literal
+    ) => {
+        $(#[$meta])*
+        pub struct $name<'a, P, R>
+        where
+            P: 'a,
+            R: RefFamily,
+        {
+            inner: $rc<Selfie<'a, P, R>>,
+        }
+
+        impl<'a, P, R> $name<'a, P, R>
+        where
+            P: 'a,
+            R: RefFamily,
+        {
+            #[doc = concat!("Moves the given [`Selfie`] behind a new [`", $rc_name, "`].")]
+            #[inline]
+            pub fn new(selfie: Selfie<'a, P, R>) -> Self {
+                Self {
+                    inner: $rc::new(selfie),
+                }
+            }
+
+            #[doc = concat!("Creates a new [`", stringify!($weak_name), "`] pointer to this [`Selfie`].")]
+            #[inline]
+            pub fn downgrade(this: &Self) -> $weak_name<'a, P, R> {
+                $weak_name {
+                    inner: $rc::downgrade(&this.inner),
+                }
+            }
+
+            /// Returns the inner [`Selfie`] if this is the only strong handle to it, or returns
+            /// this handle back otherwise.
+            #[inline]
+            pub fn try_unwrap(this: Self) -> Result<Selfie<'a, P, R>, Self> {
+                $rc::try_unwrap(this.inner).map_err(|inner| Self { inner })
+            }
+
+            /// Returns `true` if both handles point to the same [`Selfie`].
+            #[inline]
+            pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+                $rc::ptr_eq(&this.inner, &other.inner)
+            }
+
+            /// Returns the number of strong handles to this [`Selfie`].
+            #[inline]
+            pub fn strong_count(this: &Self) -> usize {
+                $rc::strong_count(&this.inner)
+            }
+        }
+
+        impl<'a, P, R> Deref for $name<'a, P, R>
+        where
+            P: 'a,
+            R: RefFamily,
+        {
+            type Target = Selfie<'a, P, R>;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                &self.inner
+            }
+        }
+
+        impl<'a, P, R> Clone for $name<'a, P, R>
+        where
+            P: 'a,
+            R: RefFamily,
+        {
+            #[inline]
+            fn clone(&self) -> Self {
+                Self {
+                    inner: self.inner.clone(),
+                }
+            }
+        }
+
+        impl<'a, P, R> From<Selfie<'a, P, R>> for $name<'a, P, R>
+        where
+            P: 'a,
+            R: RefFamily,
+        {
+            #[inline]
+            fn from(selfie: Selfie<'a, P, R>) -> Self {
+                Self::new(selfie)
+            }
+        }
+
+        impl<'a, P, R> Debug for $name<'a, P, R>
+        where
+            P: 'a,
+            R: RefFamily,
+            Selfie<'a, P, R>: Debug,
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                Debug::fmt(&*self.inner, f)
+            }
+        }
+
+        #[doc = concat!("A weak handle to a [`", stringify!($name), "`], which does not keep the [`Selfie`] alive.")]
+        pub struct $weak_name<'a, P, R>
+        where
+            P: 'a,
+            R: RefFamily,
+        {
+            inner: $weak<Selfie<'a, P, R>>,
+        }
+
+        impl<'a, P, R> $weak_name<'a, P, R>
+        where
+            P: 'a,
+            R: RefFamily,
+        {
+            #[doc = concat!("Attempts to upgrade this weak handle to a [`", stringify!($name), "`].")]
+            ///
+            /// Returns `None` if the [`Selfie`] has been dropped since.
+            #[inline]
+            pub fn upgrade(&self) -> Option<$name<'a, P, R>> {
+                self.inner.upgrade().map(|inner| $name { inner })
+            }
+        }
+
+        impl<'a, P, R> Clone for $weak_name<'a, P, R>
+        where
+            P: 'a,
+            R: RefFamily,
+        {
+            #[inline]
+            fn clone(&self) -> Self {
+                Self {
+                    inner: self.inner.clone(),
+                }
+            }
+        }
+
+        impl<'a, P, R> Debug for $weak_name<'a, P, R>
+        where
+            P: 'a,
+            R: RefFamily,
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                f.write_str(concat!("(", stringify!($weak_name), ")"))
+            }
+        }
+    };
+}
+
+reference_counted_selfie! {
+    /// A single-threaded, reference-counted handle to a [`Selfie`].
+    ///
+    /// Unlike [`Selfie::map_cloned`], cloning this handle does not create a new referential: all
+    /// clones share the same owned pointer and referential, behind a single [`Rc`].
+    ///
+    /// This dereferences to the [`Selfie`] itself, so its methods such as
+    /// [`with_referential`](Selfie::with_referential) can be called directly on the handle.
+    ///
+    /// This type is only available with the `alloc` or `std` features enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::pin::Pin;
+    /// use selfie::refs::Ref;
+    /// use selfie::{RcSelfie, Selfie};
+    ///
+    /// let data: Pin<String> = Pin::new("Hello, world!".to_owned());
+    /// let selfie: RcSelfie<String, Ref<str>> = Selfie::new(data, |s| &s[0..5]).into();
+    ///
+    /// let cloned = selfie.clone();
+    /// assert_eq!("Hello", cloned.with_referential(|r| *r));
+    ///
+    /// let weak = RcSelfie::downgrade(&selfie);
+    /// drop(selfie);
+    /// assert!(weak.upgrade().is_some());
+    ///
+    /// drop(cloned);
+    /// assert!(weak.upgrade().is_none());
+    /// ```
+    RcSelfie, WeakRcSelfie, Rc, RcWeak, "Rc"
+}
+
+reference_counted_selfie! {
+    /// A thread-safe, reference-counted handle to a [`Selfie`].
+    ///
+    /// Unlike [`Selfie::map_cloned`], cloning this handle does not create a new referential: all
+    /// clones share the same owned pointer and referential, behind a single [`Arc`].
+    ///
+    /// This dereferences to the [`Selfie`] itself, so its methods such as
+    /// [`with_referential`](Selfie::with_referential) can be called directly on the handle.
+    ///
+    /// This type is only available with the `alloc` or `std` features enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::pin::Pin;
+    /// use std::thread;
+    /// use selfie::refs::Ref;
+    /// use selfie::{ArcSelfie, Selfie};
+    ///
+    /// let data: Pin<String> = Pin::new("Hello, world!".to_owned());
+    /// let selfie: ArcSelfie<String, Ref<str>> = Selfie::new(data, |s| &s[0..5]).into();
+    ///
+    /// let cloned = selfie.clone();
+    /// let len = thread::spawn(move || cloned.with_referential(|r| r.len())).join().unwrap();
+    /// assert_eq!(5, len);
+    ///
+    /// let weak = ArcSelfie::downgrade(&selfie);
+    /// assert_eq!("Hello", weak.upgrade().unwrap().with_referential(|r| *r));
+    /// ```
+    ArcSelfie, WeakArcSelfie, Arc, ArcWeak, "Arc"
+}
+
+#[cfg(feature = "std")]
+impl<'a, P, R> ArcSelfie<'a, P, R>
+where
+    P: 'a,
+    R: RefFamily,
+{
+    /// Wraps an existing [`Arc`] into a handle. Used by [`SharedSelfie`](crate::SharedSelfie).
+    #[inline]
+    pub(crate) fn from_arc(inner: Arc<Selfie<'a, P, R>>) -> Self {
+        Self { inner }
+    }
+}
@@ -3,18 +3,21 @@
 //!
 //! These types are stand-ins that allow to name reference types without naming any associated
 //! lifetime. The full type can then be reconstructed with an arbitrary lifetime using the
-//! [`RefType`] trait.
+//! [`RefFamily`] trait, or the older [`RefType`] trait, which all [`RefFamily`] bounds accept.
 //!
 //! This is necessary for [`Selfie`](crate::Selfie), as it has to work with a self-referential
 //! lifetime, which cannot be explicitly named and has to be reconstructed internally.
 //! In essence, this is a roundabout way to achieve Higher-Kinded Polymorphism.
 //!
 //! This module provides stand-ins for common reference types, but you can create your own by
-//! implementing the [`RefType`] trait yourself.
+//! implementing the [`RefFamily`] or [`RefType`] traits yourself.
 
 use crate::{Selfie, SelfieMut};
+use core::cell::{Cell, UnsafeCell};
 use core::marker::PhantomData;
+use core::ops::{Range, RangeInclusive};
 use core::pin::Pin;
+use core::ptr::NonNull;
 
 /// A trait for reference type stand-ins to be combined with an arbitrary lifetime `'a`, to recreate
 /// the full reference type.
@@ -59,6 +62,223 @@ pub trait RefType<'a> {
     type Ref: 'a + Sized;
 }
 
+/// A trait for families of reference types, which can be combined with any lifetime `'a` to
+/// recreate the full reference type.
+///
+/// This is the trait the reference type stand-ins of [`Selfie`](crate::Selfie) and
+/// [`SelfieMut`](crate::SelfieMut) are bound by. It is the generic associated type equivalent of
+/// a `for<'a> RefType<'a>` bound, and is automatically implemented for all types implementing
+/// [`RefType`] for any lifetime.
+///
+/// New stand-ins can implement either trait. Implementing this one directly is usually simpler,
+/// but then the stand-in does not implement [`RefType`]. Such a stand-in can still be used as the
+/// referential type `R` of [`SelfieRef`] and [`SelfieRefMut`], but not as their owned pointer `P`,
+/// nor in the other generic stand-ins of this module (such as `Option<R>` or `Box<R>`), which
+/// require their parameters to implement [`RefType`].
+///
+/// # Example
+///
+/// ```
+/// use std::pin::Pin;
+/// use selfie::refs::RefFamily;
+/// use selfie::Selfie;
+///
+/// #[derive(Copy, Clone)]
+/// struct MyReferentialType<'a>(&'a str);
+///
+/// struct MyReferentialTypeStandIn;
+/// impl RefFamily for MyReferentialTypeStandIn {
+///     type Ref<'a> = MyReferentialType<'a>;
+/// }
+///
+/// let data = Pin::new("Hello, world!".to_owned());
+/// let selfie: Selfie<String, MyReferentialTypeStandIn> = Selfie::new(data, |str| MyReferentialType(&str[0..5]));
+///
+/// assert_eq!("Hello", selfie.with_referential(|r| *r).0);
+/// ```
+pub trait RefFamily {
+    /// The full reference type that is to be created when combined with the lifetime `'a`.
+    type Ref<'a>: 'a + Sized;
+}
+
+impl<R: ?Sized + for<'a> RefType<'a>> RefFamily for R {
+    type Ref<'a> = <R as RefType<'a>>::Ref;
+}
+
+/// A trait for reference type stand-ins whose referential type is covariant over its lifetime,
+/// i.e. a referential borrowed for a long lifetime can also be used as one borrowed for a shorter
+/// one.
+///
+/// This allows borrowing the referential type directly, without going through a closure.
+///
+/// Implementing this trait is safe: [`shorten_ref`](Covariant::shorten_ref) only has to return
+/// its argument as-is, which only compiles if the referential type actually is covariant.
+///
+/// # Example
+///
+/// ```
+/// use selfie::refs::{Covariant, RefType};
+///
+/// struct MyReferentialType<'a>(&'a str);
+///
+/// struct MyReferentialTypeStandIn;
+///
+/// impl<'a> RefType<'a> for MyReferentialTypeStandIn {
+///     type Ref = MyReferentialType<'a>;
+/// }
+///
+/// impl Covariant for MyReferentialTypeStandIn {
+///     fn shorten_ref<'short, 'long: 'short>(
+///         referential: &'short MyReferentialType<'long>,
+///     ) -> &'short MyReferentialType<'short> {
+///         referential
+///     }
+/// }
+/// ```
+pub trait Covariant: RefFamily {
+    /// Shortens the lifetime of a borrowed referential type from `'long` to `'short`.
+    fn shorten_ref<'short, 'long: 'short>(
+        referential: &'short Self::Ref<'long>,
+    ) -> &'short Self::Ref<'short>;
+}
+
+/// A trait for type constructors, i.e. generic containers taking a single type parameter.
+///
+/// Implementing this trait once for a container allows it to be combined with any other reference
+/// type stand-in using [`Apply`], without having to implement [`RefType`] for every possible
+/// nesting of that container.
+///
+/// Because type constructors cannot be named without their parameter, this trait is usually
+/// implemented on a separate marker type.
+///
+/// # Example
+///
+/// ```
+/// use std::pin::Pin;
+/// use selfie::refs::{Apply, Ref, TypeCtor};
+/// use selfie::Selfie;
+///
+/// struct Pair<T>(T, T);
+///
+/// // Marker type standing for the Pair type constructor
+/// struct PairCtor;
+///
+/// impl<T> TypeCtor<T> for PairCtor {
+///     type Applied = Pair<T>;
+/// }
+///
+/// let data = Pin::new("Hello, world!".to_owned());
+/// let selfie: Selfie<String, Apply<PairCtor, Ref<str>>> =
+///     Selfie::new(data, |s| Pair(&s[0..5], &s[7..]));
+///
+/// assert_eq!("Hello", selfie.with_referential(|pair| pair.0));
+/// assert_eq!("world!", selfie.with_referential(|pair| pair.1));
+/// ```
+pub trait TypeCtor<T> {
+    /// The full type that is to be created when the type constructor is applied to `T`.
+    type Applied;
+}
+
+/// A stand-in for a container described by the [`TypeCtor`] `F`, applied to the reference type
+/// stand-in `R`.
+///
+/// # Example
+///
+/// ```
+/// use std::pin::Pin;
+/// use selfie::refs::{Apply, Ref, TypeCtor};
+/// use selfie::Selfie;
+///
+/// struct VecCtor;
+///
+/// impl<T> TypeCtor<T> for VecCtor {
+///     type Applied = Vec<T>;
+/// }
+///
+/// let data = Pin::new("Hello, world!".to_owned());
+/// let selfie: Selfie<String, Apply<VecCtor, Apply<VecCtor, Ref<str>>>> =
+///     Selfie::new(data, |s| vec![s.split(", ").collect()]);
+///
+/// assert_eq!(vec![vec!["Hello", "world!"]], selfie.with_referential(|r| r.clone()));
+/// ```
+pub struct Apply<F, R>(PhantomData<F>, PhantomData<R>)
+where
+    F: ?Sized,
+    R: ?Sized;
+
+impl<'a, F, R> RefType<'a> for Apply<F, R>
+where
+    F: ?Sized + TypeCtor<R::Ref>,
+    R: ?Sized + RefType<'a>,
+    F::Applied: 'a,
+{
+    type Ref = F::Applied;
+}
+
+/// A stand-in for any type with a lifetime parameter, described by a trait object.
+///
+/// The trait object `T` is a [`RefType`] trait object for all lifetimes, which directly names the
+/// referential type, e.g. `dyn for<'a> RefType<'a, Ref = Doc<'a>>` is a stand-in for `Doc<'a>`.
+///
+/// This allows any custom type to be used in a [`Selfie`](crate::Selfie) without having to declare
+/// a bespoke stand-in type for it. The [`ForLt!`](crate::ForLt) macro can be used to write these
+/// stand-ins more concisely.
+///
+/// # Example
+///
+/// ```
+/// use std::pin::Pin;
+/// use selfie::refs::{Lt, RefType};
+/// use selfie::Selfie;
+///
+/// struct Doc<'a> {
+///     title: &'a str,
+///     body: Vec<&'a str>,
+/// }
+///
+/// let data = Pin::new("Title\nHello\nworld!".to_owned());
+/// let selfie: Selfie<String, Lt<dyn for<'a> RefType<'a, Ref = Doc<'a>>>> = Selfie::new(data, |s| {
+///     let mut lines = s.lines();
+///     let title = lines.next().unwrap();
+///
+///     Doc { title, body: lines.collect() }
+/// });
+///
+/// assert_eq!("Title", selfie.with_referential(|doc| doc.title));
+/// assert_eq!(vec!["Hello", "world!"], selfie.with_referential(|doc| doc.body.clone()));
+/// ```
+pub struct Lt<T: ?Sized>(PhantomData<T>);
+
+impl<'a, T: ?Sized + RefType<'a>> RefType<'a> for Lt<T> {
+    type Ref = T::Ref;
+}
+
+/// Creates an [`Lt`] stand-in for the given type, which can use the declared lifetime as the
+/// referential's lifetime.
+///
+/// `ForLt!(<'a> Doc<'a>)` expands to `Lt<dyn for<'a> RefType<'a, Ref = Doc<'a>>>`.
+///
+/// # Example
+///
+/// ```
+/// use std::pin::Pin;
+/// use selfie::{ForLt, Selfie};
+///
+/// struct Words<'a>(Vec<&'a str>);
+///
+/// let data = Pin::new("Hello, world!".to_owned());
+/// let selfie: Selfie<String, ForLt!(<'a> Words<'a>)> =
+///     Selfie::new(data, |s| Words(s.split(", ").collect()));
+///
+/// assert_eq!(2, selfie.with_referential(|words| words.0.len()));
+/// ```
+#[macro_export]
+macro_rules! ForLt {
+    (<$lt:lifetime> $t:ty) => {
+        $crate::refs::Lt<dyn for<$lt> $crate::refs::RefType<$lt, Ref = $t>>
+    };
+}
+
 /// A stand-in for a shared reference `&T`.
 ///
 /// # Example
@@ -79,6 +299,15 @@ impl<'a, T: 'a + ?Sized> RefType<'a> for Ref<T> {
     type Ref = &'a T;
 }
 
+impl<T: ?Sized + 'static> Covariant for Ref<T> {
+    #[inline]
+    fn shorten_ref<'short, 'long: 'short>(
+        referential: &'short <Self as RefFamily>::Ref<'long>,
+    ) -> &'short <Self as RefFamily>::Ref<'short> {
+        referential
+    }
+}
+
 /// A stand-in for a mutable reference `&mut T`.
 ///
 /// # Example
@@ -100,6 +329,15 @@ impl<'a, T: 'a + ?Sized> RefType<'a> for Mut<T> {
     type Ref = &'a mut T;
 }
 
+impl<T: ?Sized + 'static> Covariant for Mut<T> {
+    #[inline]
+    fn shorten_ref<'short, 'long: 'short>(
+        referential: &'short <Self as RefFamily>::Ref<'long>,
+    ) -> &'short <Self as RefFamily>::Ref<'short> {
+        referential
+    }
+}
+
 /// A stand-in for a [`Selfie`](crate::Selfie) holding a reference type as its owned pointer.
 ///
 /// # Example
@@ -129,7 +367,7 @@ where
 impl<'a, P, R> RefType<'a> for SelfieRef<P, R>
 where
     P: RefType<'a>,
-    R: 'a + for<'this> RefType<'this>,
+    R: 'a + RefFamily,
 {
     type Ref = Selfie<'a, P::Ref, R>;
 }
@@ -161,11 +399,375 @@ where
 impl<'a, P, R> RefType<'a> for SelfieRefMut<P, R>
 where
     P: RefType<'a>,
-    R: 'a + for<'this> RefType<'this>,
+    R: 'a + RefFamily,
 {
     type Ref = SelfieMut<'a, P::Ref, R>;
 }
 
+/// A stand-in for an owned type `T`, which does not borrow anything.
+///
+/// This is mostly useful in combination with [`SelfieRef`], to create cascaded [`Selfie`]s where
+/// an inner [`Selfie`] owns a new pointer, such as a [`Chain`](crate::Chain).
+///
+/// # Example
+///
+/// ```
+/// use std::pin::Pin;
+/// use selfie::refs::Owned;
+/// use selfie::Selfie;
+///
+/// let data = Pin::new("Hello, world!".to_owned());
+/// let selfie: Selfie<String, Owned<usize>> = Selfie::new(data, |s| s.len());
+///
+/// assert_eq!(13, selfie.with_referential(|len| *len));
+/// ```
+pub struct Owned<T: ?Sized>(PhantomData<T>);
+
+impl<'a, T: 'a> RefType<'a> for Owned<T> {
+    type Ref = T;
+}
+
+impl<T: 'static> Covariant for Owned<T> {
+    #[inline]
+    fn shorten_ref<'short, 'long: 'short>(
+        referential: &'short <Self as RefFamily>::Ref<'long>,
+    ) -> &'short <Self as RefFamily>::Ref<'short> {
+        referential
+    }
+}
+
+/// A trait for referential type stand-ins that may contain nested [`Selfie`]s, allowing to reach
+/// their innermost referential type directly.
+///
+/// This is implemented for [`SelfieRef<Ref<T>, R>`](SelfieRef) (where `R` also implements
+/// [`Flatten`]), and for the [`Ref`] and [`Mut`] stand-ins, which are the innermost referential
+/// types.
+///
+/// See [`Selfie::with_innermost`](crate::Selfie::with_innermost) and
+/// [`Selfie::flatten`](crate::Selfie::flatten) for how to use this trait.
+///
+/// # Example
+///
+/// Implementing [`Flatten`] for a custom innermost referential type:
+///
+/// ```
+/// use selfie::refs::{Flatten, RefFamily, RefType};
+///
+/// struct MyReferentialType<'a>(&'a str);
+/// struct MyReferentialTypeStandIn;
+///
+/// impl<'a> RefType<'a> for MyReferentialTypeStandIn {
+///     type Ref = MyReferentialType<'a>;
+/// }
+///
+/// impl Flatten for MyReferentialTypeStandIn {
+///     type Innermost = Self;
+///
+///     fn with_innermost<'s, 'a, F, T>(
+///         referential: &'s <Self as RefFamily>::Ref<'a>,
+///         handler: F,
+///     ) -> T
+///     where
+///         F: for<'this> FnOnce(&'s <Self as RefFamily>::Ref<'this>) -> T,
+///     {
+///         handler(referential)
+///     }
+/// }
+/// ```
+pub trait Flatten: RefFamily {
+    /// The innermost referential type stand-in.
+    type Innermost: RefFamily;
+
+    /// Performs an operation borrowing the innermost referential type of the given referential,
+    /// and returns its result.
+    fn with_innermost<'s, 'a, F, T>(referential: &'s <Self as RefFamily>::Ref<'a>, handler: F) -> T
+    where
+        F: for<'this> FnOnce(&'s <Self::Innermost as RefFamily>::Ref<'this>) -> T;
+}
+
+impl<T: ?Sized + 'static> Flatten for Ref<T> {
+    type Innermost = Self;
+
+    #[inline]
+    fn with_innermost<'s, 'a, F, U>(referential: &'s <Self as RefFamily>::Ref<'a>, handler: F) -> U
+    where
+        F: for<'this> FnOnce(&'s <Self as RefFamily>::Ref<'this>) -> U,
+    {
+        handler(referential)
+    }
+}
+
+impl<T: ?Sized + 'static> Flatten for Mut<T> {
+    type Innermost = Self;
+
+    #[inline]
+    fn with_innermost<'s, 'a, F, U>(referential: &'s <Self as RefFamily>::Ref<'a>, handler: F) -> U
+    where
+        F: for<'this> FnOnce(&'s <Self as RefFamily>::Ref<'this>) -> U,
+    {
+        handler(referential)
+    }
+}
+
+impl<T: ?Sized + 'static, R: Flatten + 'static> Flatten for SelfieRef<Ref<T>, R> {
+    type Innermost = R::Innermost;
+
+    #[inline]
+    fn with_innermost<'s, 'a, F, U>(referential: &'s <Self as RefFamily>::Ref<'a>, handler: F) -> U
+    where
+        F: for<'this> FnOnce(&'s <Self::Innermost as RefFamily>::Ref<'this>) -> U,
+    {
+        referential.with_referential(|inner| R::with_innermost(inner, handler))
+    }
+}
+
+/// A trait for referential type stand-ins that can be extended with a new, innermost [`Selfie`]
+/// level, borrowing from their current innermost reference.
+///
+/// This is implemented for [`Ref`], and for [`SelfieRef<Ref<T>, R>`](SelfieRef) (where `R` also
+/// implements [`Then`]).
+///
+/// See [`Selfie::then`](crate::Selfie::then) for how to use this trait.
+pub trait Then<R2: RefFamily>: Flatten {
+    /// The type referenced by the current innermost reference.
+    type Target: ?Sized;
+
+    /// The referential type stand-in, extended with the new innermost level.
+    type Output: RefFamily;
+
+    /// Extends the given referential with a new innermost [`Selfie`] level, created using the
+    /// given closure.
+    fn then<'a, F>(
+        referential: <Self as RefFamily>::Ref<'a>,
+        handler: F,
+    ) -> <Self::Output as RefFamily>::Ref<'a>
+    where
+        F: for<'this> FnOnce(&'this Self::Target) -> <R2 as RefFamily>::Ref<'this>;
+}
+
+impl<T, R2> Then<R2> for Ref<T>
+where
+    T: ?Sized + Unpin + 'static,
+    R2: RefFamily + 'static,
+{
+    type Target = T;
+    type Output = SelfieRef<Ref<T>, R2>;
+
+    #[inline]
+    fn then<'a, F>(
+        referential: <Self as RefFamily>::Ref<'a>,
+        handler: F,
+    ) -> <Self::Output as RefFamily>::Ref<'a>
+    where
+        F: for<'this> FnOnce(&'this T) -> <R2 as RefFamily>::Ref<'this>,
+    {
+        Selfie::new(Pin::new(referential), handler)
+    }
+}
+
+impl<T, R, R2> Then<R2> for SelfieRef<Ref<T>, R>
+where
+    T: ?Sized + 'static,
+    R: Then<R2> + 'static,
+    R::Output: 'static,
+    R2: RefFamily,
+{
+    type Target = R::Target;
+    type Output = SelfieRef<Ref<T>, R::Output>;
+
+    #[inline]
+    fn then<'a, F>(
+        referential: <Self as RefFamily>::Ref<'a>,
+        handler: F,
+    ) -> <Self::Output as RefFamily>::Ref<'a>
+    where
+        F: for<'this> FnOnce(&'this Self::Target) -> <R2 as RefFamily>::Ref<'this>,
+    {
+        referential.map(|inner, _| R::then(inner, handler))
+    }
+}
+
+/// A stand-in for a boxed closure `Box<dyn FnMut(Args) -> Out + 'a>`, which may borrow from the
+/// owned data.
+///
+/// Use [`Selfie::call`](crate::Selfie::call) or [`SelfieMut::call`](crate::SelfieMut::call) to
+/// invoke the stored closure.
+///
+/// Because the closure has to be coerced into a trait object, its construction may need to be
+/// explicitly casted to the resulting `Box<dyn FnMut>` type.
+///
+/// # Example
+///
+/// ```
+/// use std::pin::Pin;
+/// use selfie::refs::BoxFn;
+/// use selfie::SelfieMut;
+///
+/// # // Miri reports moving a Box owner as invalidating the referential, see tests/cascading.rs
+/// # if cfg!(miri) { return; }
+/// let data = Box::pin([1.0f32, 2.0, 3.0]);
+/// let mut callback: SelfieMut<Box<[f32; 3]>, BoxFn<f32, f32>> = SelfieMut::new(data, |buf| {
+///     let buf = Pin::into_inner(buf);
+///     let process = move |gain| {
+///         buf.iter_mut().for_each(|s| *s *= gain);
+///         buf.iter().sum()
+///     };
+///
+///     Box::new(process) as Box<dyn FnMut(f32) -> f32>
+/// });
+///
+/// assert_eq!(12.0, callback.call(2.0));
+/// assert_eq!([2.0, 4.0, 6.0], *callback.into_owned());
+/// ```
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub struct BoxFn<Args, Out>(PhantomData<Args>, PhantomData<Out>);
+
+/// A stand-in for a boxed, sendable closure `Box<dyn FnMut(Args) -> Out + Send + 'a>`, which may
+/// borrow from the owned data.
+///
+/// This is the same as [`BoxFn`], except the resulting [`Selfie`](crate::Selfie) or
+/// [`SelfieMut`](crate::SelfieMut) can be sent to another thread (e.g. a realtime audio thread)
+/// if its owned pointer can be as well.
+///
+/// # Example
+///
+/// ```
+/// use std::pin::Pin;
+/// use selfie::refs::SendBoxFn;
+/// use selfie::Selfie;
+///
+/// let data = Pin::new(vec![1, 2, 3]);
+/// let mut callback: Selfie<Vec<i32>, SendBoxFn<usize, i32>> =
+///     Selfie::new(data, |values| Box::new(move |i| values[i]) as Box<dyn FnMut(_) -> _ + Send>);
+///
+/// let result = std::thread::spawn(move || callback.call(2)).join().unwrap();
+/// assert_eq!(3, result);
+/// ```
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub struct SendBoxFn<Args, Out>(PhantomData<Args>, PhantomData<Out>);
+
+/// A stand-in for a slice iterator [`core::slice::Iter<T>`].
+///
+/// # Example
+///
+/// ```
+/// use std::pin::Pin;
+/// use selfie::refs::SliceIter;
+/// use selfie::Selfie;
+///
+/// let data = Pin::new(vec![1, 2, 3]);
+/// let mut selfie: Selfie<Vec<i32>, SliceIter<i32>> = Selfie::new(data, |v| v.iter());
+///
+/// assert_eq!(Some(1), selfie.with_referential_mut(|iter| iter.next().copied()));
+/// assert_eq!(5, selfie.with_referential_mut(|iter| iter.sum::<i32>()));
+/// ```
+pub struct SliceIter<T>(PhantomData<T>);
+
+impl<'a, T: 'a> RefType<'a> for SliceIter<T> {
+    type Ref = core::slice::Iter<'a, T>;
+}
+
+impl<T: 'static> Covariant for SliceIter<T> {
+    #[inline]
+    fn shorten_ref<'short, 'long: 'short>(
+        referential: &'short <Self as RefFamily>::Ref<'long>,
+    ) -> &'short <Self as RefFamily>::Ref<'short> {
+        referential
+    }
+}
+
+/// A stand-in for a mutable slice iterator [`core::slice::IterMut<T>`].
+///
+/// # Example
+///
+/// ```
+/// use std::pin::Pin;
+/// use selfie::refs::SliceIterMut;
+/// use selfie::SelfieMut;
+///
+/// let data = Pin::new(vec![1, 2, 3]);
+/// let mut selfie: SelfieMut<Vec<i32>, SliceIterMut<i32>> =
+///     SelfieMut::new(data, |v| Pin::into_inner(v).iter_mut());
+///
+/// selfie.with_referential_mut(|iter| iter.for_each(|i| *i *= 2));
+/// assert_eq!(vec![2, 4, 6], *selfie.into_owned());
+/// ```
+pub struct SliceIterMut<T>(PhantomData<T>);
+
+impl<'a, T: 'a> RefType<'a> for SliceIterMut<T> {
+    type Ref = core::slice::IterMut<'a, T>;
+}
+
+impl<T: 'static> Covariant for SliceIterMut<T> {
+    #[inline]
+    fn shorten_ref<'short, 'long: 'short>(
+        referential: &'short <Self as RefFamily>::Ref<'long>,
+    ) -> &'short <Self as RefFamily>::Ref<'short> {
+        referential
+    }
+}
+
+/// A stand-in for a string character iterator [`core::str::Chars`].
+///
+/// # Example
+///
+/// ```
+/// use std::pin::Pin;
+/// use selfie::refs::Chars;
+/// use selfie::Selfie;
+///
+/// let data = Pin::new("Hello".to_owned());
+/// let mut selfie: Selfie<String, Chars> = Selfie::new(data, |s| s.chars());
+///
+/// assert_eq!(Some('H'), selfie.with_referential_mut(|chars| chars.next()));
+/// assert_eq!("ello", selfie.with_referential(|chars| chars.as_str()));
+/// ```
+pub struct Chars;
+
+impl<'a> RefType<'a> for Chars {
+    type Ref = core::str::Chars<'a>;
+}
+
+impl Covariant for Chars {
+    #[inline]
+    fn shorten_ref<'short, 'long: 'short>(
+        referential: &'short <Self as RefFamily>::Ref<'long>,
+    ) -> &'short <Self as RefFamily>::Ref<'short> {
+        referential
+    }
+}
+
+/// A stand-in for a string character and index iterator [`core::str::CharIndices`].
+///
+/// # Example
+///
+/// ```
+/// use std::pin::Pin;
+/// use selfie::refs::CharIndices;
+/// use selfie::Selfie;
+///
+/// let data = Pin::new("Hé!".to_owned());
+/// let mut selfie: Selfie<String, CharIndices> = Selfie::new(data, |s| s.char_indices());
+///
+/// assert_eq!(Some((0, 'H')), selfie.with_referential_mut(|chars| chars.next()));
+/// assert_eq!(Some((1, 'é')), selfie.with_referential_mut(|chars| chars.next()));
+/// assert_eq!(Some((3, '!')), selfie.with_referential_mut(|chars| chars.next()));
+/// ```
+pub struct CharIndices;
+
+impl<'a> RefType<'a> for CharIndices {
+    type Ref = core::str::CharIndices<'a>;
+}
+
+impl Covariant for CharIndices {
+    #[inline]
+    fn shorten_ref<'short, 'long: 'short>(
+        referential: &'short <Self as RefFamily>::Ref<'long>,
+    ) -> &'short <Self as RefFamily>::Ref<'short> {
+        referential
+    }
+}
+
 // Other std types
 
 impl<'a, R: RefType<'a>> RefType<'a> for Option<R> {
@@ -176,6 +778,151 @@ impl<'a, R: RefType<'a>> RefType<'a> for Pin<R> {
     type Ref = Pin<R::Ref>;
 }
 
+/// A stand-in for a [`PhantomData`] marker of a reference type, e.g. `PhantomData<&'a T>`.
+///
+/// # Example
+///
+/// ```
+/// use std::marker::PhantomData;
+/// use std::pin::Pin;
+/// use selfie::refs::Ref;
+/// use selfie::Selfie;
+///
+/// let data = Pin::new("Hello, world!".to_owned());
+/// let selfie: Selfie<String, PhantomData<Ref<str>>> = Selfie::new(data, |_| PhantomData);
+///
+/// assert_eq!("Hello, world!", selfie.owned());
+/// ```
+impl<'a, R: RefType<'a>> RefType<'a> for PhantomData<R> {
+    type Ref = PhantomData<R::Ref>;
+}
+
+/// A stand-in for a range of reference types, e.g. `Range<&'a T>`.
+///
+/// # Example
+///
+/// ```
+/// use std::ops::Range;
+/// use std::pin::Pin;
+/// use selfie::refs::Ref;
+/// use selfie::Selfie;
+///
+/// let data = Pin::new(vec![1, 2, 3, 4]);
+/// let selfie: Selfie<Vec<i32>, Range<Ref<i32>>> = Selfie::new(data, |v| &v[1]..&v[3]);
+///
+/// selfie.with_referential(|window| {
+///     assert_eq!(2, *window.start);
+///     assert_eq!(4, *window.end);
+/// });
+/// ```
+impl<'a, R: RefType<'a>> RefType<'a> for Range<R> {
+    type Ref = Range<R::Ref>;
+}
+
+/// A stand-in for an inclusive range of reference types, e.g. `RangeInclusive<&'a T>`.
+///
+/// # Example
+///
+/// ```
+/// use std::ops::RangeInclusive;
+/// use std::pin::Pin;
+/// use selfie::refs::Ref;
+/// use selfie::Selfie;
+///
+/// let data = Pin::new(vec![1, 2, 3, 4]);
+/// let selfie: Selfie<Vec<i32>, RangeInclusive<Ref<i32>>> =
+///     Selfie::new(data, |v| &v[1]..=&v[3]);
+///
+/// selfie.with_referential(|window| {
+///     assert_eq!(2, **window.start());
+///     assert_eq!(4, **window.end());
+/// });
+/// ```
+impl<'a, R: RefType<'a>> RefType<'a> for RangeInclusive<R> {
+    type Ref = RangeInclusive<R::Ref>;
+}
+
+/// A stand-in for a [`Cell`] holding a reference type, e.g. `Cell<&'a T>`.
+///
+/// Note that a shared reference to a [`Cell`] (`&'a Cell<T>`) can be expressed using the
+/// [`Ref<Cell<T>>`](Ref) stand-in instead.
+///
+/// # Example
+///
+/// ```
+/// use std::cell::Cell;
+/// use std::pin::Pin;
+/// use selfie::refs::Ref;
+/// use selfie::Selfie;
+///
+/// let data = Pin::new("Hello, world!".to_owned());
+/// let selfie: Selfie<String, Cell<Ref<str>>> = Selfie::new(data, |s| Cell::new(&s[0..5]));
+///
+/// selfie.with_referential(|cell| assert_eq!("Hello", cell.get()));
+/// selfie.with_referential(|cell| cell.set(&cell.get()[3..]));
+/// selfie.with_referential(|cell| assert_eq!("lo", cell.get()));
+/// ```
+impl<'a, R: RefType<'a>> RefType<'a> for Cell<R> {
+    type Ref = Cell<R::Ref>;
+}
+
+/// A stand-in for an [`UnsafeCell`] holding a reference type, e.g. `UnsafeCell<&'a T>`.
+///
+/// Note that a shared reference to an [`UnsafeCell`] (`&'a UnsafeCell<T>`) can be expressed using
+/// the [`Ref<UnsafeCell<T>>`](Ref) stand-in instead.
+///
+/// # Example
+///
+/// ```
+/// use std::cell::UnsafeCell;
+/// use std::pin::Pin;
+/// use selfie::refs::Ref;
+/// use selfie::Selfie;
+///
+/// let data = Pin::new("Hello, world!".to_owned());
+/// let mut selfie: Selfie<String, UnsafeCell<Ref<str>>> =
+///     Selfie::new(data, |s| UnsafeCell::new(&s[0..5]));
+///
+/// assert_eq!("Hello", selfie.with_referential_mut(|cell| *cell.get_mut()));
+/// ```
+impl<'a, R: RefType<'a>> RefType<'a> for UnsafeCell<R> {
+    type Ref = UnsafeCell<R::Ref>;
+}
+
+/// A stand-in for a [`NonNull`] pointer to the owned data (or anything inside it).
+///
+/// Unlike the other stand-ins, [`NonNull`] does not carry any lifetime: it is up to the user to
+/// make sure the pointer is not used after the [`Selfie`](crate::Selfie) it was taken from has been
+/// dropped.
+///
+/// # Example
+///
+/// ```
+/// use std::pin::Pin;
+/// use std::ptr::NonNull;
+/// use selfie::Selfie;
+///
+/// # // Miri reports moving a Box owner as invalidating the referential, see tests/cascading.rs
+/// # if cfg!(miri) { return; }
+/// let data = Box::pin(42);
+/// let selfie: Selfie<Box<i32>, NonNull<i32>> = Selfie::new(data, |i| NonNull::from(i));
+///
+/// // SAFETY: the pointer comes from the selfie's owned data, which is still alive.
+/// assert_eq!(42, selfie.with_referential(|ptr| unsafe { *ptr.as_ref() }));
+/// ```
+impl<'a, T: ?Sized + 'a> RefType<'a> for NonNull<T> {
+    type Ref = NonNull<T>;
+}
+
+impl<T: ?Sized + 'static> Covariant for NonNull<T> {
+    #[inline]
+    fn shorten_ref<'short, 'long: 'short>(
+        referential: &'short <Self as RefFamily>::Ref<'long>,
+    ) -> &'short <Self as RefFamily>::Ref<'short> {
+        referential
+    }
+}
+
 #[cfg(any(feature = "alloc", feature = "std"))]
 mod alloc_impl {
     extern crate alloc;
@@ -196,4 +943,12 @@ mod alloc_impl {
     impl<'a, R: RefType<'a>> RefType<'a> for Arc<R> {
         type Ref = Arc<R::Ref>;
     }
+
+    impl<'a, Args: 'a, Out: 'a> RefType<'a> for BoxFn<Args, Out> {
+        type Ref = Box<dyn FnMut(Args) -> Out + 'a>;
+    }
+
+    impl<'a, Args: 'a, Out: 'a> RefType<'a> for SendBoxFn<Args, Out> {
+        type Ref = Box<dyn FnMut(Args) -> Out + Send + 'a>;
+    }
 }
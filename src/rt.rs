@@ -0,0 +1,234 @@
+//! Lock-free and allocation-free handoff of [`Selfie`]s between threads.
+//!
+//! This module provides a single-producer, single-consumer triple buffer, which allows a producer
+//! thread to publish values (usually [`Selfie`]s) to a consumer thread (e.g. a realtime thread)
+//! without ever locking or allocating, once the buffer has been created.
+//!
+//! Values that have been superseded by a newer one are never dropped by the consumer: instead,
+//! they are handed back to the producer, which is responsible for dropping them (or reusing them).
+//!
+//! This module is only available with the `alloc` or `std` features enabled.
+//!
+//! # Example
+//!
+//! ```
+//! use std::pin::Pin;
+//! use std::thread;
+//! use selfie::refs::Ref;
+//! use selfie::{rt, Selfie};
+//!
+//! struct Preset {
+//!     name: String,
+//!     gain: f32,
+//! }
+//!
+//! # // Miri reports moving a Box owner as invalidating the referential, see tests/cascading.rs
+//! # if cfg!(miri) { return; }
+//! let (mut producer, mut consumer) = rt::triple_buffer::<Selfie<Box<Preset>, Ref<f32>>>();
+//!
+//! let worker = thread::spawn(move || {
+//!     let preset = Box::pin(Preset { name: "Loud".to_owned(), gain: 2.0 });
+//!     let retired = producer.publish(Selfie::new(preset, |p| &p.gain));
+//!     drop(retired); // Retired values are dropped on the producer side
+//! });
+//!
+//! worker.join().unwrap();
+//!
+//! // On the realtime thread
+//! assert!(consumer.update());
+//! assert_eq!(Some(2.0), consumer.with_referential(|gain| **gain));
+//! ```
+
+#![allow(unsafe_code)] // Sharing slots between threads requires manual synchronization
+
+extern crate alloc;
+
+use crate::refs::RefFamily;
+use crate::Selfie;
+use alloc::sync::Arc;
+use core::cell::UnsafeCell;
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicU8, Ordering};
+use stable_deref_trait::StableDeref;
+
+/// Mask of the slot index part of the shared middle state.
+const INDEX_MASK: u8 = 0b011;
+/// Flag set on the shared middle state when it holds a value the consumer hasn't picked up yet.
+const NEW_FLAG: u8 = 0b100;
+
+struct Shared<T> {
+    slots: [UnsafeCell<Option<T>>; 3],
+    middle: AtomicU8,
+}
+
+// SAFETY: each slot is only ever accessed by the side (producer or consumer) that currently owns
+// its index. Ownership of the indices is transferred through atomic swaps of the middle state.
+unsafe impl<T: Send> Sync for Shared<T> {}
+
+impl<T> Shared<T> {
+    #[inline]
+    fn slot(&self, index: u8) -> *mut Option<T> {
+        self.slots[(index & INDEX_MASK) as usize].get()
+    }
+}
+
+/// Creates a new, empty triple buffer, and returns its [`Producer`] and [`Consumer`] halves.
+///
+/// This is the only operation of this module that allocates.
+///
+/// See the [module documentation](self) for an example.
+pub fn triple_buffer<T: Send>() -> (Producer<T>, Consumer<T>) {
+    let shared = Arc::new(Shared {
+        slots: [
+            UnsafeCell::new(None),
+            UnsafeCell::new(None),
+            UnsafeCell::new(None),
+        ],
+        middle: AtomicU8::new(1),
+    });
+
+    let producer = Producer {
+        shared: shared.clone(),
+        back: 0,
+    };
+
+    let consumer = Consumer {
+        shared,
+        front: 2,
+        _marker: PhantomData,
+    };
+
+    (producer, consumer)
+}
+
+/// The producer half of a triple buffer, created by [`triple_buffer`].
+///
+/// Dropping a value returned by this type (e.g. by [`Producer::publish`]) is the responsibility of
+/// the producer thread.
+pub struct Producer<T> {
+    shared: Arc<Shared<T>>,
+    back: u8,
+}
+
+impl<T> Producer<T> {
+    /// Publishes a new value to the consumer, superseding any previously published value.
+    ///
+    /// This returns a retired value if there is one: either a value that was previously published
+    /// but never picked up by the consumer, or a value the consumer has replaced by a newer one.
+    ///
+    /// This never locks nor allocates.
+    pub fn publish(&mut self, value: T) -> Option<T> {
+        // SAFETY: the back slot is owned by the producer, and always left empty
+        unsafe { *self.shared.slot(self.back) = Some(value) };
+
+        let previous = self
+            .shared
+            .middle
+            .swap(self.back | NEW_FLAG, Ordering::AcqRel);
+        self.back = previous & INDEX_MASK;
+
+        // SAFETY: the new back slot has just been handed over to the producer
+        unsafe { (*self.shared.slot(self.back)).take() }
+    }
+
+    /// Collects a value the consumer has replaced by a newer one, if there is one.
+    ///
+    /// This allows retired values to be dropped without waiting for the next call to
+    /// [`Producer::publish`].
+    ///
+    /// This never locks nor allocates.
+    pub fn collect(&mut self) -> Option<T> {
+        let middle = self.shared.middle.load(Ordering::Acquire);
+
+        // If the consumer hasn't picked up the latest value yet, it hasn't retired anything.
+        if middle & NEW_FLAG != 0 {
+            return None;
+        }
+
+        self.shared
+            .middle
+            .compare_exchange(middle, self.back, Ordering::AcqRel, Ordering::Acquire)
+            .ok()?;
+        self.back = middle & INDEX_MASK;
+
+        // SAFETY: the new back slot has just been handed over to the producer
+        unsafe { (*self.shared.slot(self.back)).take() }
+    }
+}
+
+impl<T> Debug for Producer<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Producer").finish_non_exhaustive()
+    }
+}
+
+/// The consumer half of a triple buffer, created by [`triple_buffer`].
+///
+/// The consumer never drops any value it has received, except if it is dropped after the
+/// [`Producer`]: in that case, the remaining values are dropped along with it.
+pub struct Consumer<T> {
+    shared: Arc<Shared<T>>,
+    front: u8,
+    // The consumer hands out references to T, therefore it can only be Sync if T is.
+    _marker: PhantomData<T>,
+}
+
+impl<T> Consumer<T> {
+    /// Picks up the latest value published by the producer, if there is a new one.
+    ///
+    /// The current value is then handed back to the producer. Returns `true` if the current value
+    /// was updated.
+    ///
+    /// This never locks, allocates nor drops any value.
+    pub fn update(&mut self) -> bool {
+        if self.shared.middle.load(Ordering::Acquire) & NEW_FLAG == 0 {
+            return false;
+        }
+
+        let previous = self.shared.middle.swap(self.front, Ordering::AcqRel);
+        self.front = previous & INDEX_MASK;
+
+        true
+    }
+
+    /// Returns a reference to the current value, or `None` if no value has been picked up yet.
+    #[inline]
+    pub fn get(&self) -> Option<&T> {
+        // SAFETY: the front slot is owned by the consumer
+        unsafe { (*self.shared.slot(self.front)).as_ref() }
+    }
+
+    /// Returns a mutable reference to the current value, or `None` if no value has been picked up
+    /// yet.
+    #[inline]
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: the front slot is owned by the consumer
+        unsafe { (*self.shared.slot(self.front)).as_mut() }
+    }
+}
+
+impl<'a, P, R> Consumer<Selfie<'a, P, R>>
+where
+    P: StableDeref + 'a,
+    R: RefFamily,
+    P::Target: 'a,
+{
+    /// Performs an operation borrowing the referential type `R` of the current [`Selfie`], and
+    /// returns its result, or `None` if no value has been picked up yet.
+    ///
+    /// See the [module documentation](self) for an example.
+    #[inline]
+    pub fn with_referential<'s, F, T>(&'s self, handler: F) -> Option<T>
+    where
+        F: for<'this> FnOnce(&'s <R as RefFamily>::Ref<'this>) -> T,
+    {
+        self.get().map(|selfie| selfie.with_referential(handler))
+    }
+}
+
+impl<T> Debug for Consumer<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Consumer").finish_non_exhaustive()
+    }
+}
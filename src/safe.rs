@@ -1,17 +1,18 @@
 //! Safe implementations for Selfie and SelfieMut that do not rely on anything internal to it
 
 use crate::refs::*;
-use crate::{Selfie, SelfieMut};
+use crate::{Selfie, SelfieMut, SelfieMutGuard, ZippedSelfie};
 use core::fmt::{Debug, Formatter};
 use core::ops::DerefMut;
-use stable_deref_trait::StableDeref;
+use core::pin::Pin;
+use stable_deref_trait::{CloneStableDeref, StableDeref};
 
 impl<'a, P, R> Debug for Selfie<'a, P, R>
 where
     P::Target: Debug,
-    for<'this> <R as RefType<'this>>::Ref: Debug,
+    for<'this> <R as RefFamily>::Ref<'this>: Debug,
     P: 'a + StableDeref,
-    R: for<'this> RefType<'this>,
+    R: RefFamily,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         self.with_referential(|referential| {
@@ -25,9 +26,9 @@ where
 
 impl<'a, P, R> Debug for SelfieMut<'a, P, R>
 where
-    for<'this> <R as RefType<'this>>::Ref: Debug,
+    for<'this> <R as RefFamily>::Ref<'this>: Debug,
     P: StableDeref + DerefMut + 'a,
-    R: for<'this> RefType<'this>,
+    R: RefFamily,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         self.with_referential(|referential| {
@@ -37,3 +38,350 @@ where
         })
     }
 }
+
+impl<'a, P1, P2, R> Debug for ZippedSelfie<'a, P1, P2, R>
+where
+    P1::Target: Debug,
+    P2::Target: Debug,
+    for<'this> <R as RefFamily>::Ref<'this>: Debug,
+    P1: 'a + StableDeref,
+    P2: 'a + StableDeref,
+    R: RefFamily,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.with_referential(|referential| {
+            f.debug_struct("ZippedSelfie")
+                .field("owned", &self.owned())
+                .field("referential", referential)
+                .finish()
+        })
+    }
+}
+
+impl<'s, T: ?Sized + Debug> Debug for SelfieMutGuard<'s, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<'a, P, R> Selfie<'a, P, R>
+where
+    P: StableDeref + 'a,
+    R: RefFamily,
+    P::Target: 'a,
+{
+    /// Calls the closure stored as the referential type `R` with the given arguments, and returns
+    /// its result.
+    ///
+    /// This is a shorthand for `selfie.with_referential_mut(|f| f(args))`, and works with any
+    /// referential that implements [`FnMut`], such as [`BoxFn`] or [`SendBoxFn`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::BoxFn;
+    /// use selfie::Selfie;
+    ///
+    /// let data = Pin::new("Hello, world!".to_owned());
+    /// let mut selfie: Selfie<String, BoxFn<char, usize>> = Selfie::new(data, |s| {
+    ///     Box::new(move |c| s.matches(c).count()) as Box<dyn FnMut(_) -> _>
+    /// });
+    ///
+    /// assert_eq!(3, selfie.call('l'));
+    /// assert_eq!(2, selfie.call('o'));
+    /// ```
+    #[inline]
+    pub fn call<Args, Out>(&mut self, args: Args) -> Out
+    where
+        for<'this> <R as RefFamily>::Ref<'this>: FnMut(Args) -> Out,
+    {
+        self.with_referential_mut(|f| f(args))
+    }
+}
+
+impl<'a, P, R> Selfie<'a, P, R>
+where
+    P: StableDeref + 'a,
+    R: RefFamily,
+    P::Target: 'a,
+{
+    /// Creates a new [`Selfie`] by consuming this [`Selfie`]'s reference type `R` and producing a
+    /// shared reference `&T`, using a given closure.
+    ///
+    /// This is the same as [`map`](Selfie::map) with a [`Ref<T>`] referential type, which does not
+    /// require any type annotations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::Selfie;
+    ///
+    /// let data = Pin::new("Hello, world!".to_owned());
+    /// let selfie = Selfie::new_str(data, |str| &str[0..5]);
+    ///
+    /// let selfie = selfie.map_ref(|str, _| &str[3..]);
+    /// assert_eq!("lo", selfie.with_referential(|s| *s));
+    /// ```
+    #[inline]
+    pub fn map_ref<T, F>(self, mapper: F) -> Selfie<'a, P, Ref<T>>
+    where
+        T: ?Sized + 'static,
+        F: for<'this> FnOnce(<R as RefFamily>::Ref<'this>, &'this P::Target) -> &'this T,
+    {
+        self.map::<Ref<T>, _>(mapper)
+    }
+}
+
+impl<'a, P, T> Selfie<'a, P, Ref<T>>
+where
+    P: StableDeref + 'a,
+    P::Target: 'a,
+    T: ?Sized + 'static,
+{
+    /// Creates a new [`Selfie`] from a pinned pointer `P`, and a closure to create a shared
+    /// reference `&T` from a shared reference to the data behind `P`.
+    ///
+    /// This is the same as [`Selfie::new`] with a [`Ref<T>`] referential type, which does not
+    /// require any type annotations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::Selfie;
+    ///
+    /// struct Person {
+    ///     name: String,
+    /// }
+    ///
+    /// let data = Box::pin(Person { name: "Ferris".to_owned() });
+    /// let selfie = Selfie::new_ref(data, |p| p.name.as_str());
+    ///
+    /// assert_eq!("Ferris", selfie.with_referential(|r| *r));
+    /// ```
+    #[inline]
+    pub fn new_ref<F>(owned: Pin<P>, handler: F) -> Self
+    where
+        F: for<'this> FnOnce(&'this P::Target) -> &'this T,
+    {
+        Self::new(owned, handler)
+    }
+}
+
+impl<'a, P> Selfie<'a, P, Ref<str>>
+where
+    P: StableDeref + 'a,
+    P::Target: 'a,
+{
+    /// Creates a new [`Selfie`] from a pinned pointer `P`, and a closure to create a string slice
+    /// from a shared reference to the data behind `P`.
+    ///
+    /// This is the same as [`Selfie::new_ref`], except the closure's return value is known to be a
+    /// `&str`, which allows it to be coerced from e.g. a `&String`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::Selfie;
+    ///
+    /// let data = Pin::new("Hello, world!".to_owned());
+    /// let selfie = Selfie::new_str(data, |s| s);
+    ///
+    /// assert_eq!("Hello, world!", selfie.with_referential(|r| *r));
+    /// ```
+    #[inline]
+    pub fn new_str<F>(owned: Pin<P>, handler: F) -> Self
+    where
+        F: for<'this> FnOnce(&'this P::Target) -> &'this str,
+    {
+        Self::new(owned, handler)
+    }
+}
+
+impl<'a, P, T> Selfie<'a, P, Ref<[T]>>
+where
+    P: StableDeref + 'a,
+    P::Target: 'a,
+    T: 'static,
+{
+    /// Creates a new [`Selfie`] from a pinned pointer `P`, and a closure to create a slice from a
+    /// shared reference to the data behind `P`.
+    ///
+    /// This is the same as [`Selfie::new_ref`], except the closure's return value is known to be a
+    /// slice, which allows it to be coerced from e.g. a `&Vec<T>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::Selfie;
+    ///
+    /// let data = Pin::new(vec![1, 2, 3]);
+    /// let selfie = Selfie::new_slice(data, |v| v);
+    ///
+    /// assert_eq!(&[1, 2, 3], selfie.with_referential(|r| *r));
+    /// ```
+    #[inline]
+    pub fn new_slice<F>(owned: Pin<P>, handler: F) -> Self
+    where
+        F: for<'this> FnOnce(&'this P::Target) -> &'this [T],
+    {
+        Self::new(owned, handler)
+    }
+}
+
+impl<'a, P, T> Selfie<'a, P, Ref<T>>
+where
+    P: StableDeref + CloneStableDeref + 'a,
+    P::Target: 'a,
+    T: ?Sized + 'static,
+{
+    /// Creates a new [`Selfie`] projecting this [`Selfie`]'s reference into another referential
+    /// type (`R2`), using a given closure.
+    ///
+    /// This is similar to [`map_cloned`](Selfie::map_cloned): only the owned pointer `P` is cloned,
+    /// and this [`Selfie`] is kept unchanged. However, the closure directly receives the current
+    /// reference, which makes it easy to hand out narrower views into the same data, e.g. into a
+    /// single field.
+    ///
+    /// This is only available for [`Ref<T>`] referentials, not for other stand-ins such as
+    /// [`SliceIter`] or custom ones. The closure's result must borrow for the self-referential
+    /// lifetime, which can only be expressed when that lifetime appears in the closure's input
+    /// type as `&'this T`, and not only through a [`RefFamily`] projection. For other referential
+    /// types, [`map_cloned`](Selfie::map_cloned) can be used instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use selfie::refs::Ref;
+    /// use selfie::Selfie;
+    ///
+    /// struct Config {
+    ///     name: String,
+    ///     values: Vec<u32>,
+    /// }
+    ///
+    /// let data = Rc::pin(Config { name: "config".to_owned(), values: vec![1, 2, 3] });
+    /// let config: Selfie<Rc<Config>, Ref<Config>> = Selfie::new(data, |c| c);
+    ///
+    /// let name = config.project::<Ref<str>, _>(|c| &c.name);
+    /// let values: Selfie<Rc<Config>, Ref<[u32]>> = config.project(|c| &c.values[1..]);
+    ///
+    /// drop(config);
+    /// assert_eq!("config", name.with_referential(|r| *r));
+    /// assert_eq!(&[2, 3], values.with_referential(|r| *r));
+    /// ```
+    #[inline]
+    pub fn project<R2: RefFamily, F>(&self, projector: F) -> Selfie<'a, P, R2>
+    where
+        F: for<'this> FnOnce(&'this T) -> <R2 as RefFamily>::Ref<'this>,
+    {
+        self.map_cloned(|referential, _| projector(*referential))
+    }
+}
+
+impl<'a, P, R> SelfieMut<'a, P, R>
+where
+    P: StableDeref + DerefMut + 'a,
+    R: RefFamily,
+{
+    /// Creates a new [`Selfie`] by consuming this [`SelfieMut`]'s reference type `R` and producing
+    /// a shared reference `&T`, using a given closure.
+    ///
+    /// This is the same as [`map`](SelfieMut::map) with a [`Ref<T>`] referential type, which does
+    /// not require any type annotations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::SelfieMut;
+    ///
+    /// let data = Pin::new("Hello, world!".to_owned());
+    /// let selfie = SelfieMut::new_mut(data, |str| &mut Pin::into_inner(str)[0..5]);
+    ///
+    /// let selfie = selfie.map_ref(|str, _| &str[3..]);
+    /// assert_eq!("lo", selfie.with_referential(|s| *s));
+    /// ```
+    #[inline]
+    pub fn map_ref<T, F>(self, mapper: F) -> Selfie<'a, P, Ref<T>>
+    where
+        T: ?Sized + 'static,
+        F: for<'this> FnOnce(<R as RefFamily>::Ref<'this>, &'this ()) -> &'this T,
+    {
+        self.map::<Ref<T>, _>(mapper)
+    }
+
+    /// Calls the closure stored as the referential type `R` with the given arguments, and returns
+    /// its result.
+    ///
+    /// This is a shorthand for `selfie.with_referential_mut(|f| f(args))`, and works with any
+    /// referential that implements [`FnMut`], such as [`BoxFn`] or [`SendBoxFn`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::BoxFn;
+    /// use selfie::SelfieMut;
+    ///
+    /// # // Miri reports moving a Box owner as invalidating the referential, see tests/cascading.rs
+    /// # if cfg!(miri) { return; }
+    /// let data = Box::pin(0);
+    /// let mut counter: SelfieMut<Box<i32>, BoxFn<i32, i32>> = SelfieMut::new(data, |count| {
+    ///     let count = Pin::into_inner(count);
+    ///     let increment = move |increment| {
+    ///         *count += increment;
+    ///         *count
+    ///     };
+    ///
+    ///     Box::new(increment) as Box<dyn FnMut(_) -> _>
+    /// });
+    ///
+    /// assert_eq!(2, counter.call(2));
+    /// assert_eq!(5, counter.call(3));
+    /// ```
+    #[inline]
+    pub fn call<Args, Out>(&mut self, args: Args) -> Out
+    where
+        for<'this> <R as RefFamily>::Ref<'this>: FnMut(Args) -> Out,
+    {
+        self.with_referential_mut(|f| f(args))
+    }
+}
+
+impl<'a, P, T> SelfieMut<'a, P, Mut<T>>
+where
+    P: StableDeref + DerefMut + 'a,
+    T: ?Sized + 'static,
+{
+    /// Creates a new [`SelfieMut`] from a pinned pointer `P`, and a closure to create a mutable
+    /// reference `&mut T` from a pinned, exclusive reference to the data behind `P`.
+    ///
+    /// This is the same as [`SelfieMut::new`] with a [`Mut<T>`] referential type, which does not
+    /// require any type annotations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::SelfieMut;
+    ///
+    /// let data = Pin::new("Hello, world!".to_owned());
+    /// let mut selfie = SelfieMut::new_mut(data, |str| &mut Pin::into_inner(str)[0..5]);
+    ///
+    /// selfie.with_referential_mut(|s| s.make_ascii_uppercase());
+    /// assert_eq!("HELLO", selfie.with_referential(|s| &**s));
+    /// ```
+    #[inline]
+    pub fn new_mut<F>(owned: Pin<P>, handler: F) -> Self
+    where
+        F: for<'this> FnOnce(Pin<&'this mut P::Target>) -> &'this mut T,
+    {
+        Self::new(owned, handler)
+    }
+}
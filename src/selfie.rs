@@ -6,10 +6,11 @@
 
 #![allow(unsafe_code)] // I'll be glad to remove this the day self-referential structs can be implemented in Safe Rust
 
+use crate::future::AsyncHandler;
 use crate::refs::*;
 use crate::utils::*;
 use crate::SelfieError;
-use core::ops::DerefMut;
+use core::ops::{Deref, DerefMut};
 use core::pin::Pin;
 use stable_deref_trait::{CloneStableDeref, StableDeref};
 
@@ -38,9 +39,15 @@ use stable_deref_trait::{CloneStableDeref, StableDeref};
 /// in Rust's current lifetime system.
 ///
 /// Also because of the non-nameable self-referential lifetime, `R` is not the referential type
-/// itself, but a stand-in that implements [`RefType`] (e.g. [`Ref<T>`](Ref) instead of `&T`).
+/// itself, but a stand-in that implements [`RefFamily`] (e.g. [`Ref<T>`](Ref) instead of `&T`).
 /// See the [`refs`](crate::refs) module for some reference type stand-ins this library provides, or see
-/// the [`RefType`] trait documentation for how to implement your own.
+/// the [`RefFamily`] and [`RefType`] trait documentation for how to implement your own.
+///
+/// # Panic safety
+///
+/// The referential type `R` is always dropped before the owned pointer `P`, including when a
+/// closure given to a constructor or a mapping method (such as [`map`](Selfie::map)) panics: in
+/// that case, any referential the closure owns is dropped first, followed by the owned pointer.
 ///
 /// # Example
 ///
@@ -60,21 +67,25 @@ use stable_deref_trait::{CloneStableDeref, StableDeref};
 pub struct Selfie<'a, P, R>
 where
     P: 'a,
-    R: for<'this> RefType<'this>,
+    R: RefFamily,
 {
     // SAFETY: enforce drop order!
+    // Constructors and mapping methods don't need a drop guard for panic safety: they bind the
+    // owned pointer to a local before calling the user closure, and locals are only dropped after
+    // the closure's own frame (and any referential it holds) has unwound. The same goes for the
+    // futures of the async constructors, which are dropped before the owned pointer on cancellation.
     // SAFETY: Note that Ref's lifetime isn't actually ever 'a: it is the unnameable 'this instead.
     // Marking it as 'a is a trick to be able to store it and still name the whole type.
     // It is *absolutely* unsound to ever use this field as 'a, it should immediately be casted
     // to and from 'this instead.
-    referential: <R as RefType<'a>>::Ref,
+    referential: R::Ref<'a>,
     owned: Pin<P>,
 }
 
 impl<'a, P, R> Selfie<'a, P, R>
 where
     P: StableDeref + 'a,
-    R: for<'this> RefType<'this>,
+    R: RefFamily,
     P::Target: 'a,
 {
     /// Creates a new [`Selfie`] from a pinned pointer `P`, and a closure to create the reference
@@ -83,6 +94,10 @@ where
     /// Note the closure cannot expect to be called with a specific lifetime, as it will handle
     /// the unnameable `'this` lifetime instead.
     ///
+    /// The reference type `R` usually cannot be inferred from the closure alone. For plain
+    /// references, [`new_ref`](Selfie::new_ref), [`new_str`](Selfie::new_str) and
+    /// [`new_slice`](Selfie::new_slice) do not require any type annotations.
+    ///
     /// # Example
     ///
     /// ```
@@ -99,15 +114,14 @@ where
     #[inline]
     pub fn new<F>(owned: Pin<P>, handler: F) -> Self
     where
-        F: for<'this> FnOnce(&'this P::Target) -> <R as RefType<'this>>::Ref,
+        F: for<'this> FnOnce(&'this P::Target) -> R::Ref<'this>,
     {
         // SAFETY: This type does not expose anything that could expose referential longer than owned exists
         let detached = unsafe { detach_lifetime(owned.as_ref()) }.get_ref();
 
-        Self {
-            referential: handler(detached),
-            owned,
-        }
+        let referential = handler(detached);
+
+        Self { referential, owned }
     }
 
     /// Creates a new [`Selfie`] from a pinned pointer `P`, and a fallible closure to create the
@@ -138,7 +152,7 @@ where
     #[inline]
     pub fn try_new<E, F>(owned: Pin<P>, handler: F) -> Result<Self, SelfieError<P, E>>
     where
-        F: for<'this> FnOnce(&'this P::Target) -> Result<<R as RefType<'this>>::Ref, E>,
+        F: for<'this> FnOnce(&'this P::Target) -> Result<R::Ref<'this>, E>,
     {
         // SAFETY: This type does not expose anything that could expose referential longer than owned exists
         let detached = unsafe { detach_lifetime(owned.as_ref()) }.get_ref();
@@ -151,6 +165,163 @@ where
         Ok(Self { referential, owned })
     }
 
+    /// Creates a new [`Selfie`] from a pinned pointer `P`, and a fallible closure to create the
+    /// reference type `R` from a shared reference to the data behind `P`, whose error may also
+    /// borrow from that data.
+    ///
+    /// Note the closure cannot expect to be called with a specific lifetime, as it will handle
+    /// the unnameable `'this` lifetime instead.
+    ///
+    /// # Errors
+    ///
+    /// The closure can return a [`Result`] containing either the referential type, or an error
+    /// type borrowing the data behind `P`. Because of this, the error type is also a reference type
+    /// stand-in `E`, like `R`.
+    ///
+    /// If the closure returns an `Err`, it will be returned as a [`Selfie`] holding the original
+    /// owned pointer type alongside the error, so that the error remains valid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::{Ref, RefType};
+    /// use selfie::Selfie;
+    ///
+    /// struct ParseError<'a> {
+    ///     token: &'a str,
+    /// }
+    ///
+    /// struct ParseErrorRef;
+    ///
+    /// impl<'a> RefType<'a> for ParseErrorRef {
+    ///     type Ref = ParseError<'a>;
+    /// }
+    ///
+    /// fn parse(input: &str) -> Result<&str, ParseError<'_>> {
+    ///     input
+    ///         .split_whitespace()
+    ///         .find(|token| token.parse::<u32>().is_err())
+    ///         .map_or(Ok(input), |token| Err(ParseError { token }))
+    /// }
+    ///
+    /// let data = Pin::new("1 2 three 4".to_owned());
+    /// let result: Result<Selfie<String, Ref<str>>, Selfie<String, ParseErrorRef>> =
+    ///     Selfie::try_new_borrowed_err(data, |s| parse(s));
+    ///
+    /// let error = result.unwrap_err();
+    /// assert_eq!("three", error.with_referential(|e| e.token));
+    /// assert_eq!("1 2 three 4", error.owned());
+    /// ```
+    #[inline]
+    pub fn try_new_borrowed_err<E, F>(owned: Pin<P>, handler: F) -> Result<Self, Selfie<'a, P, E>>
+    where
+        E: RefFamily,
+        F: for<'this> FnOnce(&'this P::Target) -> Result<R::Ref<'this>, E::Ref<'this>>,
+    {
+        // SAFETY: This type does not expose anything that could expose referential longer than owned exists
+        let detached = unsafe { detach_lifetime(owned.as_ref()) }.get_ref();
+
+        match handler(detached) {
+            Ok(referential) => Ok(Self { referential, owned }),
+            Err(error) => Err(Selfie {
+                referential: error,
+                owned,
+            }),
+        }
+    }
+
+    /// Asynchronously creates a new [`Selfie`] from a pinned pointer `P`, and an asynchronous
+    /// function to create the reference type `R` from a shared reference to the data behind `P`.
+    ///
+    /// The [`Selfie`] is created once the future returned by the function resolves.
+    ///
+    /// Because the future borrows the data behind `P` with the unnameable `'this` lifetime, the
+    /// function has to be generic over it, such as an `async fn`. See [`AsyncHandler`] for more
+    /// information.
+    ///
+    /// If the returned future is dropped before completion, the future returned by the function
+    /// is dropped before the owned pointer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::Ref;
+    /// use selfie::Selfie;
+    ///
+    /// async fn decode(input: &str) -> &str {
+    ///     &input[0..5]
+    /// }
+    ///
+    /// # async fn run() {
+    /// let data = Pin::new("Hello, world!".to_owned());
+    /// let selfie: Selfie<String, Ref<str>> = Selfie::new_async(data, decode).await;
+    ///
+    /// assert_eq!("Hello", selfie.with_referential(|r| *r));
+    /// # }
+    /// ```
+    pub async fn new_async<F>(owned: Pin<P>, handler: F) -> Selfie<'a, P, R>
+    where
+        F: for<'this> AsyncHandler<'this, P::Target, R::Ref<'this>>,
+    {
+        // SAFETY: This type does not expose anything that could expose referential longer than owned exists
+        let detached = unsafe { detach_lifetime(owned.as_ref()) }.get_ref();
+        let referential = handler.call(detached).await;
+
+        Selfie { referential, owned }
+    }
+
+    /// Asynchronously creates a new [`Selfie`] from a pinned pointer `P`, and a fallible
+    /// asynchronous function to create the reference type `R` from a shared reference to the data
+    /// behind `P`.
+    ///
+    /// This is the asynchronous equivalent of [`Selfie::try_new`]. See [`Selfie::new_async`] for
+    /// more information.
+    ///
+    /// # Errors
+    ///
+    /// The future can resolve to a [`Result`] containing either the referential type, or any error
+    /// type. If it resolves to an `Err`, it will be returned in a [`SelfieError`] alongside the
+    /// original owned pointer type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::Ref;
+    /// use selfie::{Selfie, SelfieError};
+    ///
+    /// async fn decode(input: &str) -> Result<&str, ()> {
+    ///     input.get(0..5).ok_or(())
+    /// }
+    ///
+    /// # async fn run() {
+    /// let data = Pin::new("Hello, world!".to_owned());
+    /// let selfie: Result<Selfie<String, Ref<str>>, SelfieError<String, ()>>
+    ///     = Selfie::try_new_async(data, decode).await;
+    ///
+    /// assert_eq!("Hello", selfie.unwrap().with_referential(|r| *r));
+    /// # }
+    /// ```
+    pub async fn try_new_async<E, F>(
+        owned: Pin<P>,
+        handler: F,
+    ) -> Result<Selfie<'a, P, R>, SelfieError<P, E>>
+    where
+        F: for<'this> AsyncHandler<'this, P::Target, Result<R::Ref<'this>, E>>,
+    {
+        // SAFETY: This type does not expose anything that could expose referential longer than owned exists
+        let detached = unsafe { detach_lifetime(owned.as_ref()) }.get_ref();
+
+        let referential = match handler.call(detached).await {
+            Ok(r) => r,
+            Err(error) => return Err(SelfieError { owned, error }),
+        };
+
+        Ok(Selfie { referential, owned })
+    }
+
     /// Returns a shared reference to the owned type by de-referencing `P`.
     ///
     /// # Example
@@ -185,7 +356,7 @@ where
     #[inline]
     pub fn with_referential<'s, F, T>(&'s self, handler: F) -> T
     where
-        F: for<'this> FnOnce(&'s <R as RefType<'this>>::Ref) -> T,
+        F: for<'this> FnOnce(&'s R::Ref<'this>) -> T,
     {
         // SAFETY: Down-casting is safe here, because Ref is actually 's, not 'a
         let referential = unsafe { downcast_ref::<'s, 'a, R>(&self.referential) };
@@ -216,7 +387,7 @@ where
     #[inline]
     pub fn with_referential_mut<'s, F, T>(&'s mut self, handler: F) -> T
     where
-        F: for<'this> FnOnce(&'s mut <R as RefType<'this>>::Ref) -> T,
+        F: for<'this> FnOnce(&'s mut R::Ref<'this>) -> T,
     {
         // SAFETY: Down-casting is safe here, because Ref is actually 's, not 'a
         let referential = unsafe { downcast_mut::<'s, 'a, R>(&mut self.referential) };
@@ -250,7 +421,8 @@ where
     /// is also provided to the closure for convenience.
     ///
     /// This method consumes the [`Selfie`]. If you need to keep it intact, see
-    /// [`map_cloned`](Selfie::map_cloned).
+    /// [`map_cloned`](Selfie::map_cloned). For plain references, [`map_ref`](Selfie::map_ref)
+    /// does not require any type annotations.
     ///
     /// # Example
     ///
@@ -270,12 +442,9 @@ where
     /// assert_eq!("world!", selfie.with_referential(|r| *r));
     /// ```
     #[inline]
-    pub fn map<R2: for<'this> RefType<'this>, F>(self, mapper: F) -> Selfie<'a, P, R2>
+    pub fn map<R2: RefFamily, F>(self, mapper: F) -> Selfie<'a, P, R2>
     where
-        F: for<'this> FnOnce(
-            <R as RefType<'this>>::Ref,
-            &'this P::Target,
-        ) -> <R2 as RefType<'this>>::Ref,
+        F: for<'this> FnOnce(R::Ref<'this>, &'this P::Target) -> R2::Ref<'this>,
     {
         // SAFETY: here we break the lifetime guarantees: we must be very careful to not drop owned before referential
         let Self { owned, referential } = self;
@@ -320,15 +489,12 @@ where
     /// assert_eq!("world!", selfie.unwrap().with_referential(|r| *r));
     /// ```
     #[inline]
-    pub fn try_map<R2: for<'this> RefType<'this>, E, F>(
+    pub fn try_map<R2: RefFamily, E, F>(
         self,
         mapper: F,
     ) -> Result<Selfie<'a, P, R2>, SelfieError<P, E>>
     where
-        F: for<'this> FnOnce(
-            <R as RefType<'this>>::Ref,
-            &'this P::Target,
-        ) -> Result<<R2 as RefType<'this>>::Ref, E>,
+        F: for<'this> FnOnce(R::Ref<'this>, &'this P::Target) -> Result<R2::Ref<'this>, E>,
     {
         // SAFETY: here we break the lifetime guarantees: we must be very careful to not drop owned before referential
         let Self { owned, referential } = self;
@@ -371,12 +537,9 @@ where
     /// second_selfie.with_referential(|s| assert_eq!("lo", *s)); // New one still works
     /// ```
     #[inline]
-    pub fn map_cloned<R2: for<'this> RefType<'this>, F>(&self, mapper: F) -> Selfie<'a, P, R2>
+    pub fn map_cloned<R2: RefFamily, F>(&self, mapper: F) -> Selfie<'a, P, R2>
     where
-        F: for<'this> FnOnce(
-            &<R as RefType<'this>>::Ref,
-            &'this P::Target,
-        ) -> <R2 as RefType<'this>>::Ref,
+        F: for<'this> FnOnce(&R::Ref<'this>, &'this P::Target) -> R2::Ref<'this>,
         P: CloneStableDeref,
     {
         let owned = self.owned.clone();
@@ -421,15 +584,9 @@ where
     /// second_selfie.with_referential(|s| assert_eq!("lo", *s)); // New one still works
     /// ```
     #[inline]
-    pub fn try_map_cloned<R2: for<'this> RefType<'this>, E, F>(
-        &self,
-        mapper: F,
-    ) -> Result<Selfie<'a, P, R2>, E>
+    pub fn try_map_cloned<R2: RefFamily, E, F>(&self, mapper: F) -> Result<Selfie<'a, P, R2>, E>
     where
-        F: for<'this> FnOnce(
-            &<R as RefType<'this>>::Ref,
-            &'this P::Target,
-        ) -> Result<<R2 as RefType<'this>>::Ref, E>,
+        F: for<'this> FnOnce(&R::Ref<'this>, &'this P::Target) -> Result<R2::Ref<'this>, E>,
         P: CloneStableDeref,
     {
         let owned = self.owned.clone();
@@ -440,6 +597,130 @@ where
 
         Ok(Selfie { owned, referential })
     }
+
+    /// Moves this [`Selfie`] and another one into a single [`ZippedSelfie`], with a new referential
+    /// type (`R3`) produced from both of their referential types, using a given closure.
+    ///
+    /// Both owned pointers are left unchanged, and shared references to the data behind them are
+    /// also provided to the closure for convenience.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::{Ref, RefType};
+    /// use selfie::{Selfie, ZippedSelfie};
+    ///
+    /// struct Lookup<'a> {
+    ///     dictionary: &'a [String],
+    ///     document: &'a str,
+    /// }
+    ///
+    /// struct LookupRef;
+    ///
+    /// impl<'a> RefType<'a> for LookupRef {
+    ///     type Ref = Lookup<'a>;
+    /// }
+    ///
+    /// let dictionary: Pin<Vec<String>> = Pin::new(vec!["hello".to_owned(), "world".to_owned()]);
+    /// let dictionary: Selfie<Vec<String>, Ref<[String]>> = Selfie::new(dictionary, |d| &d[..]);
+    ///
+    /// let document: Pin<String> = Pin::new("hello, selfie".to_owned());
+    /// let document: Selfie<String, Ref<str>> = Selfie::new(document, |d| &d[0..5]);
+    ///
+    /// let lookup: ZippedSelfie<Vec<String>, String, LookupRef> =
+    ///     dictionary.zip(document, |dictionary, document, _, _| Lookup { dictionary, document });
+    ///
+    /// assert!(lookup.with_referential(|l| l.dictionary.iter().any(|w| w == l.document)));
+    /// ```
+    #[inline]
+    pub fn zip<P2, R2, R3, F>(
+        self,
+        other: Selfie<'a, P2, R2>,
+        combine: F,
+    ) -> ZippedSelfie<'a, P, P2, R3>
+    where
+        P2: StableDeref + 'a,
+        P2::Target: 'a,
+        R2: RefFamily,
+        R3: RefFamily,
+        F: for<'this> FnOnce(
+            R::Ref<'this>,
+            R2::Ref<'this>,
+            &'this P::Target,
+            &'this P2::Target,
+        ) -> R3::Ref<'this>,
+    {
+        // SAFETY: here we break the lifetime guarantees: we must be very careful to not drop owned before referential
+        let Self { owned, referential } = self;
+        let Selfie {
+            owned: other_owned,
+            referential: other_referential,
+        } = other;
+
+        // Locals are dropped in reverse order, so if the closure panics, two separate bindings would
+        // drop other_owned first. Grouping them drops both in the same order as ZippedSelfie.
+        let owned = (owned, other_owned);
+
+        // SAFETY: This type does not expose anything that could expose referential longer than owned exists
+        let detached = unsafe { detach_lifetime(owned.0.as_ref()) }.get_ref();
+        // SAFETY: same as above
+        let other_detached = unsafe { detach_lifetime(owned.1.as_ref()) }.get_ref();
+
+        let referential = combine(referential, other_referential, detached, other_detached);
+        let (owned, other_owned) = owned;
+
+        ZippedSelfie {
+            referential,
+            owned,
+            other_owned,
+        }
+    }
+
+    /// Returns a shared reference to the referential type `R`, with its lifetime shortened to the
+    /// borrow of this [`Selfie`].
+    ///
+    /// Unlike [`with_referential`](Selfie::with_referential), values borrowed from the returned
+    /// reference can flow out like normal borrows of this [`Selfie`].
+    ///
+    /// This is only possible for [`Covariant`] referential types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::Ref;
+    /// use selfie::Selfie;
+    ///
+    /// let data: Pin<String> = Pin::new("Hello, world!".to_owned());
+    /// let selfie: Selfie<String, Ref<str>> = Selfie::new(data, |s| &s[0..5]);
+    ///
+    /// let hello: &str = selfie.get();
+    /// assert_eq!("Hello", hello);
+    /// ```
+    #[inline]
+    pub fn get<'s>(&'s self) -> &'s R::Ref<'s>
+    where
+        R: Covariant,
+    {
+        // The stored referential isn't really 'a, but it is valid for the 's borrow of self. The
+        // Covariant impl shortens it, and only compiles if the referential type is covariant.
+        R::shorten_ref::<'s, 'a>(&self.referential)
+    }
+
+    /// Performs an operation borrowing both the data behind `P` and mutably borrowing the
+    /// referential type `R`, and returns its result.
+    #[cfg(feature = "compat")]
+    #[inline]
+    pub(crate) fn with_owned_and_referential_mut<'s, F, T>(&'s mut self, handler: F) -> T
+    where
+        F: for<'this> FnOnce(&'s P::Target, &'s mut R::Ref<'this>) -> T,
+    {
+        let owned = self.owned.as_ref().get_ref();
+        // SAFETY: Down-casting is safe here, because Ref is actually 's, not 'a
+        let referential = unsafe { downcast_mut::<'s, 'a, R>(&mut self.referential) };
+        handler(owned, referential)
+    }
 }
 
 /// A self-referential struct with a mutable reference (`R`) to an object owned by a pinned pointer (`P`).
@@ -467,9 +748,15 @@ where
 /// in Rust's current lifetime system.
 ///
 /// Also because of the non-nameable self-referential lifetime, `R` is not the referential type
-/// itself, but a stand-in that implements [`RefType`] (e.g. [`Ref<T>`](Ref) instead of `&T`).
+/// itself, but a stand-in that implements [`RefFamily`] (e.g. [`Ref<T>`](Ref) instead of `&T`).
 /// See the [`refs`](crate::refs) module for some reference type stand-ins this library provides, or see
-/// the [`RefType`] trait documentation for how to implement your own.
+/// the [`RefFamily`] and [`RefType`] trait documentation for how to implement your own.
+///
+/// # Panic safety
+///
+/// The referential type `R` is always dropped before the owned pointer `P`, including when a
+/// closure given to a constructor or a mapping method (such as [`map`](SelfieMut::map)) panics: in
+/// that case, any referential the closure owns is dropped first, followed by the owned pointer.
 ///
 /// # Example
 ///
@@ -489,17 +776,17 @@ where
 pub struct SelfieMut<'a, P, R>
 where
     P: 'a,
-    R: for<'this> RefType<'this>,
+    R: RefFamily,
 {
     // SAFETY: enforce drop order!
-    referential: <R as RefType<'a>>::Ref,
+    referential: R::Ref<'a>,
     owned: Pin<P>,
 }
 
 impl<'a, P, R> SelfieMut<'a, P, R>
 where
     P: StableDeref + DerefMut + 'a,
-    R: for<'this> RefType<'this>,
+    R: RefFamily,
 {
     /// Creates a new [`SelfieMut`] from a pinned pointer `P`, and a closure to create the reference
     /// type `R` from a pinned, exclusive reference to the data behind `P`.
@@ -522,15 +809,14 @@ where
     /// ```
     pub fn new<F>(mut owned: Pin<P>, handler: F) -> Self
     where
-        F: for<'this> FnOnce(Pin<&'this mut P::Target>) -> <R as RefType<'this>>::Ref,
+        F: for<'this> FnOnce(Pin<&'this mut P::Target>) -> R::Ref<'this>,
     {
         // SAFETY: This type does not expose anything that could expose referential longer than owned exists
         let detached = unsafe { detach_lifetime_mut(owned.as_mut()) };
 
-        Self {
-            referential: handler(detached),
-            owned,
-        }
+        let referential = handler(detached);
+
+        Self { referential, owned }
     }
 
     /// Creates a new [`SelfieMut`] from a pinned pointer `P`, and a fallible closure to create the
@@ -561,7 +847,7 @@ where
     #[inline]
     pub fn try_new<E, F>(mut owned: Pin<P>, handler: F) -> Result<Self, SelfieError<P, E>>
     where
-        F: for<'this> FnOnce(Pin<&'this mut P::Target>) -> Result<<R as RefType<'this>>::Ref, E>,
+        F: for<'this> FnOnce(Pin<&'this mut P::Target>) -> Result<R::Ref<'this>, E>,
     {
         // SAFETY: This type does not expose anything that could expose referential longer than owned exists
         let detached = unsafe { detach_lifetime_mut(owned.as_mut()) };
@@ -590,7 +876,7 @@ where
     #[inline]
     pub fn with_referential<'s, F, T>(&'s self, handler: F) -> T
     where
-        F: for<'this> FnOnce(&'s <R as RefType<'this>>::Ref) -> T,
+        F: for<'this> FnOnce(&'s R::Ref<'this>) -> T,
     {
         // SAFETY: Down-casting is safe here, because Ref is actually 's, not 'a
         let referential = unsafe { downcast_ref::<'s, 'a, R>(&self.referential) };
@@ -619,13 +905,73 @@ where
     #[inline]
     pub fn with_referential_mut<'s, F, T>(&'s mut self, handler: F) -> T
     where
-        F: for<'this> FnOnce(&'s mut <R as RefType<'this>>::Ref) -> T,
+        F: for<'this> FnOnce(&'s mut R::Ref<'this>) -> T,
     {
         // SAFETY: Down-casting is safe here, because Ref is actually 's, not 'a
         let referential = unsafe { downcast_mut::<'s, 'a, R>(&mut self.referential) };
         handler(referential)
     }
 
+    /// Returns a shared reference to the referential type `R`, with its lifetime shortened to the
+    /// borrow of this [`SelfieMut`].
+    ///
+    /// This is only possible for [`Covariant`] referential types. See [`Selfie::get`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::{refs::Mut, SelfieMut};
+    ///
+    /// let data: Pin<String> = Pin::new("Hello, world!".to_owned());
+    /// let selfie: SelfieMut<String, Mut<str>> = SelfieMut::new(data, |s| &mut Pin::into_inner(s)[0..5]);
+    ///
+    /// let hello: &str = selfie.get();
+    /// assert_eq!("Hello", hello);
+    /// ```
+    #[inline]
+    pub fn get<'s>(&'s self) -> &'s R::Ref<'s>
+    where
+        R: Covariant,
+    {
+        // The stored referential isn't really 'a, but it is valid for the 's borrow of self. The
+        // Covariant impl shortens it, and only compiles if the referential type is covariant.
+        R::shorten_ref::<'s, 'a>(&self.referential)
+    }
+
+    /// Returns a guard mutably borrowing the data the referential type `R` points to.
+    ///
+    /// The referential type itself can never be mutably borrowed with a shortened lifetime, as
+    /// that would allow replacing it with a shorter-lived one. Instead, this is only available for
+    /// referential types which [`DerefMut`] to a target `T` that does not depend on their lifetime,
+    /// such as [`Mut<T>`](crate::refs::Mut).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::pin::Pin;
+    /// use selfie::{refs::Mut, SelfieMut};
+    ///
+    /// let data: Pin<String> = Pin::new("Hello, world!".to_owned());
+    /// let mut selfie: SelfieMut<String, Mut<str>> = SelfieMut::new(data, |s| &mut Pin::into_inner(s)[0..5]);
+    ///
+    /// let mut guard = selfie.get_mut();
+    /// guard.make_ascii_uppercase();
+    /// assert_eq!("HELLO", &*guard);
+    ///
+    /// let data = Pin::into_inner(selfie.into_owned());
+    /// assert_eq!("HELLO, world!", &data);
+    /// ```
+    #[inline]
+    pub fn get_mut<T: ?Sized>(&mut self) -> SelfieMutGuard<'_, T>
+    where
+        for<'this> R::Ref<'this>: DerefMut<Target = T>,
+    {
+        SelfieMutGuard {
+            target: self.with_referential_mut(|r| &mut **r),
+        }
+    }
+
     /// Unwraps the [`SelfieMut`] by dropping the reference type `R`, and returning the owned pointer
     /// type `P`, as it was passed to the constructor.
     ///
@@ -668,12 +1014,12 @@ where
     /// selfie.with_referential(|s| assert_eq!("lo", *s));
     /// ```
     #[inline]
-    pub fn map<R2: for<'this> RefType<'this>, F>(self, mapper: F) -> Selfie<'a, P, R2>
+    pub fn map<R2: RefFamily, F>(self, mapper: F) -> Selfie<'a, P, R2>
     where
         F: for<'this> FnOnce(
-            <R as RefType<'this>>::Ref,
+            R::Ref<'this>,
             &'this (), // This is needed to constrain the lifetime TODO: find a way to remove this
-        ) -> <R2 as RefType<'this>>::Ref,
+        ) -> R2::Ref<'this>,
     {
         // SAFETY: here we break the lifetime guarantees: we must be very careful to not drop owned before referential
         let Self { owned, referential } = self;
@@ -711,15 +1057,15 @@ where
     /// selfie.with_referential(|s| assert_eq!("lo", *s));
     /// ```
     #[inline]
-    pub fn try_map<R2: for<'this> RefType<'this>, E, F>(
+    pub fn try_map<R2: RefFamily, E, F>(
         self,
         mapper: F,
     ) -> Result<Selfie<'a, P, R2>, SelfieError<P, E>>
     where
         F: for<'this> FnOnce(
-            <R as RefType<'this>>::Ref,
+            R::Ref<'this>,
             &'this (), // This is needed to constrain the lifetime TODO: find a way to remove this
-        ) -> Result<<R2 as RefType<'this>>::Ref, E>,
+        ) -> Result<R2::Ref<'this>, E>,
     {
         // SAFETY: here we break the lifetime guarantees: we must be very careful to not drop owned before referential
         let Self { owned, referential } = self;
@@ -731,4 +1077,208 @@ where
 
         Ok(Selfie { owned, referential })
     }
+
+    /// Creates a new [`SelfieMut`] by consuming this [`SelfieMut`]'s reference type `R` and
+    /// producing a mutable reference `&mut T`, using a given closure.
+    ///
+    /// Unlike [`map`](SelfieMut::map), this returns a [`SelfieMut`], so that the data behind `P`
+    /// stays exclusively borrowed by the new mutable reference.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::SelfieMut;
+    ///
+    /// let data = Pin::new("Hello, world!".to_owned());
+    /// let selfie = SelfieMut::new_mut(data, |str| &mut Pin::into_inner(str)[0..5]);
+    ///
+    /// let mut selfie = selfie.map_mut(|str, _| &mut str[3..]);
+    /// selfie.with_referential_mut(|s| s.make_ascii_uppercase());
+    ///
+    /// let data = Pin::into_inner(selfie.into_owned());
+    /// assert_eq!("HelLO, world!", &data);
+    /// ```
+    #[inline]
+    pub fn map_mut<T, F>(self, mapper: F) -> SelfieMut<'a, P, Mut<T>>
+    where
+        T: ?Sized + 'static,
+        F: for<'this> FnOnce(
+            R::Ref<'this>,
+            &'this (), // This is needed to constrain the lifetime TODO: find a way to remove this
+        ) -> &'this mut T,
+    {
+        // SAFETY: here we break the lifetime guarantees: we must be very careful to not drop owned before referential
+        let Self { owned, referential } = self;
+
+        let referential = mapper(referential, &());
+
+        SelfieMut { owned, referential }
+    }
+}
+
+/// A self-referential struct with a shared reference (`R`) to the objects owned by two pinned
+/// pointers (`P1` and `P2`).
+///
+/// A [`ZippedSelfie`] is constructed by combining two [`Selfie`]s using [`Selfie::zip`].
+///
+/// When dropped, the referential type `R` is dropped first, followed by the data behind `P1` and
+/// the data behind `P2`, in that order.
+pub struct ZippedSelfie<'a, P1, P2, R>
+where
+    P1: 'a,
+    P2: 'a,
+    R: RefFamily,
+{
+    // SAFETY: enforce drop order!
+    referential: R::Ref<'a>,
+    owned: Pin<P1>,
+    other_owned: Pin<P2>,
+}
+
+impl<'a, P1, P2, R> ZippedSelfie<'a, P1, P2, R>
+where
+    P1: StableDeref + 'a,
+    P2: StableDeref + 'a,
+    R: RefFamily,
+    P1::Target: 'a,
+    P2::Target: 'a,
+{
+    /// Returns shared references to the data behind both owned pointers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::Ref;
+    /// use selfie::{Selfie, ZippedSelfie};
+    ///
+    /// let first: Selfie<String, Ref<str>> = Selfie::new(Pin::new("Hello".to_owned()), |s| s);
+    /// let second: Selfie<String, Ref<str>> = Selfie::new(Pin::new("world!".to_owned()), |s| s);
+    ///
+    /// let zipped: ZippedSelfie<String, String, Ref<str>> = first.zip(second, |a, b, _, _| {
+    ///     if a.len() > b.len() { a } else { b }
+    /// });
+    ///
+    /// assert_eq!(("Hello", "world!"), zipped.owned());
+    /// ```
+    #[inline]
+    pub fn owned(&self) -> (&P1::Target, &P2::Target) {
+        (
+            self.owned.as_ref().get_ref(),
+            self.other_owned.as_ref().get_ref(),
+        )
+    }
+
+    /// Performs an operation borrowing the referential type `R`, and returns its result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::Ref;
+    /// use selfie::{Selfie, ZippedSelfie};
+    ///
+    /// let first: Selfie<String, Ref<str>> = Selfie::new(Pin::new("Hello".to_owned()), |s| s);
+    /// let second: Selfie<String, Ref<str>> = Selfie::new(Pin::new("world!".to_owned()), |s| s);
+    ///
+    /// let zipped: ZippedSelfie<String, String, Ref<str>> = first.zip(second, |a, b, _, _| {
+    ///     if a.len() > b.len() { a } else { b }
+    /// });
+    ///
+    /// assert_eq!("world!", zipped.with_referential(|r| *r));
+    /// ```
+    #[inline]
+    pub fn with_referential<'s, F, T>(&'s self, handler: F) -> T
+    where
+        F: for<'this> FnOnce(&'s R::Ref<'this>) -> T,
+    {
+        // SAFETY: Down-casting is safe here, because Ref is actually 's, not 'a
+        let referential = unsafe { downcast_ref::<'s, 'a, R>(&self.referential) };
+        handler(referential)
+    }
+
+    /// Performs an operation mutably borrowing the referential type `R`, and returns its result.
+    ///
+    /// Note that this operation *cannot* mutably access the data behind `P1` nor `P2`, it only
+    /// mutates the referential type `R` itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::Ref;
+    /// use selfie::{Selfie, ZippedSelfie};
+    ///
+    /// let first: Selfie<String, Ref<str>> = Selfie::new(Pin::new("Hello".to_owned()), |s| s);
+    /// let second: Selfie<String, Ref<str>> = Selfie::new(Pin::new("world!".to_owned()), |s| s);
+    ///
+    /// let mut zipped: ZippedSelfie<String, String, Ref<str>> = first.zip(second, |a, b, _, _| {
+    ///     if a.len() > b.len() { a } else { b }
+    /// });
+    ///
+    /// zipped.with_referential_mut(|r| {
+    ///     let s = *r;
+    ///     *r = &s[..5];
+    /// });
+    /// assert_eq!("world", zipped.with_referential(|r| *r));
+    /// ```
+    #[inline]
+    pub fn with_referential_mut<'s, F, T>(&'s mut self, handler: F) -> T
+    where
+        F: for<'this> FnOnce(&'s mut R::Ref<'this>) -> T,
+    {
+        // SAFETY: Down-casting is safe here, because Ref is actually 's, not 'a
+        let referential = unsafe { downcast_mut::<'s, 'a, R>(&mut self.referential) };
+        handler(referential)
+    }
+
+    /// Unwraps this [`ZippedSelfie`] by dropping its referential type `R`, and returning both
+    /// owned pointers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::Pin;
+    /// use selfie::refs::Ref;
+    /// use selfie::{Selfie, ZippedSelfie};
+    ///
+    /// let first: Selfie<String, Ref<str>> = Selfie::new(Pin::new("Hello".to_owned()), |s| s);
+    /// let second: Selfie<String, Ref<str>> = Selfie::new(Pin::new("world!".to_owned()), |s| s);
+    ///
+    /// let zipped: ZippedSelfie<String, String, Ref<str>> = first.zip(second, |a, b, _, _| {
+    ///     if a.len() > b.len() { a } else { b }
+    /// });
+    ///
+    /// let (first, second) = zipped.into_owned();
+    /// assert_eq!("Hello", &*first);
+    /// assert_eq!("world!", &*second);
+    /// ```
+    #[inline]
+    pub fn into_owned(self) -> (Pin<P1>, Pin<P2>) {
+        (self.owned, self.other_owned)
+    }
+}
+
+/// A guard mutably borrowing the data pointed to by the referential type of a [`SelfieMut`].
+///
+/// This is created by [`SelfieMut::get_mut`], and dereferences to the referential's target.
+pub struct SelfieMutGuard<'s, T: ?Sized> {
+    target: &'s mut T,
+}
+
+impl<'s, T: ?Sized> Deref for SelfieMutGuard<'s, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.target
+    }
+}
+
+impl<'s, T: ?Sized> DerefMut for SelfieMutGuard<'s, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        self.target
+    }
 }
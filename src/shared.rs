@@ -0,0 +1,181 @@
+//! A replaceable [`Selfie`], shared between threads.
+
+#![allow(unsafe_code)] // The current Selfie is shared between loads and stores as a raw Arc pointer
+
+extern crate std;
+
+use crate::refs::RefFamily;
+use crate::{ArcSelfie, Selfie};
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// A [`Selfie`] shared between threads, which can be atomically replaced by a new one at any time.
+///
+/// Readers [`load`](SharedSelfie::load) an [`ArcSelfie`] handle to the current [`Selfie`], which
+/// they can keep using even if a new one is [`store`](SharedSelfie::store)d in the meantime: the
+/// replaced [`Selfie`] is only dropped once its last handle is.
+///
+/// Loads are lock-free: they never wait for a store or for each other, and only take a few atomic
+/// operations. Stores atomically swap the current [`Selfie`], then wait for the loads that may
+/// still be reading the previous one (but not for the handles they returned) before releasing it.
+/// Concurrent stores are applied one after the other.
+///
+/// This is typically used with [`Arc`] owned pointers, for e.g. configuration hot-reloading.
+///
+/// This type is only available with the `std` feature enabled.
+///
+/// # Example
+///
+/// ```
+/// use std::pin::Pin;
+/// use std::sync::Arc;
+/// use selfie::refs::Ref;
+/// use selfie::{Selfie, SharedSelfie};
+///
+/// let config: Pin<Arc<String>> = Arc::pin("verbose=true".to_owned());
+/// let shared: SharedSelfie<Arc<String>, Ref<str>> =
+///     SharedSelfie::new(Selfie::new(config, |c| &c[8..]));
+///
+/// let handle = shared.load();
+/// assert_eq!("true", handle.with_referential(|r| *r));
+///
+/// let config: Pin<Arc<String>> = Arc::pin("verbose=false".to_owned());
+/// shared.store(Selfie::new(config, |c| &c[8..]));
+///
+/// // Existing handles still see the previous value
+/// assert_eq!("true", handle.with_referential(|r| *r));
+/// assert_eq!("false", shared.load().with_referential(|r| *r));
+/// ```
+pub struct SharedSelfie<'a, P, R>
+where
+    P: 'a,
+    R: RefFamily,
+{
+    // The current Selfie, as returned by Arc::into_raw. It holds one strong count.
+    current: AtomicPtr<Selfie<'a, P, R>>,
+    // The epoch new loads enter, either 0 or 1.
+    epoch: AtomicUsize,
+    // The number of loads in progress in each epoch.
+    readers: [AtomicUsize; 2],
+    // Stores are serialized, so that only one of them switches epochs at a time.
+    store: Mutex<()>,
+    // Makes this type Send and Sync only if the Arc it holds is.
+    _arc: PhantomData<Arc<Selfie<'a, P, R>>>,
+}
+
+impl<'a, P, R> SharedSelfie<'a, P, R>
+where
+    P: 'a,
+    R: RefFamily,
+{
+    /// Creates a new [`SharedSelfie`], initially holding the given [`Selfie`].
+    #[inline]
+    pub fn new(selfie: Selfie<'a, P, R>) -> Self {
+        Self {
+            current: AtomicPtr::new(Arc::into_raw(Arc::new(selfie)) as *mut _),
+            epoch: AtomicUsize::new(0),
+            readers: [AtomicUsize::new(0), AtomicUsize::new(0)],
+            store: Mutex::new(()),
+            _arc: PhantomData,
+        }
+    }
+
+    /// Returns a handle to the current [`Selfie`].
+    ///
+    /// The handle keeps that [`Selfie`] alive, even if it is replaced in the meantime.
+    ///
+    /// This never blocks, even while a store is in progress.
+    pub fn load(&self) -> ArcSelfie<'a, P, R> {
+        let epoch = self.enter();
+
+        let current = self.current.load(Ordering::SeqCst);
+        // SAFETY: current comes from Arc::into_raw, and a store does not release it until every
+        // load in our epoch has left it, so its strong count cannot have dropped to zero yet.
+        let handle = unsafe {
+            Arc::increment_strong_count(current);
+            Arc::from_raw(current)
+        };
+
+        self.readers[epoch].fetch_sub(1, Ordering::SeqCst);
+        ArcSelfie::from_arc(handle)
+    }
+
+    /// Replaces the current [`Selfie`] by the given one.
+    ///
+    /// The previous [`Selfie`] is dropped as soon as no handle to it remains.
+    #[inline]
+    pub fn store(&self, selfie: Selfie<'a, P, R>) {
+        drop(self.swap(selfie))
+    }
+
+    /// Replaces the current [`Selfie`] by the given one, and returns a handle to the previous one.
+    pub fn swap(&self, selfie: Selfie<'a, P, R>) -> ArcSelfie<'a, P, R> {
+        let new = Arc::into_raw(Arc::new(selfie)) as *mut _;
+
+        // Nothing that could panic runs while this is held
+        let _store = self.store.lock().unwrap_or_else(PoisonError::into_inner);
+        let previous = self.current.swap(new, Ordering::SeqCst);
+
+        // Loads that may have read the previous pointer have all entered the current epoch. New
+        // loads enter the other one, so this only waits for loads already in progress.
+        let epoch = self.epoch.load(Ordering::SeqCst);
+        self.epoch.store(epoch ^ 1, Ordering::SeqCst);
+        while self.readers[epoch].load(Ordering::SeqCst) != 0 {
+            std::thread::yield_now();
+        }
+
+        // SAFETY: previous comes from Arc::into_raw, and its strong count is now handed over to
+        // the returned handle, since no load can still be reading it.
+        ArcSelfie::from_arc(unsafe { Arc::from_raw(previous) })
+    }
+
+    /// Registers a load in the current epoch, and returns that epoch.
+    #[inline]
+    fn enter(&self) -> usize {
+        loop {
+            let epoch = self.epoch.load(Ordering::SeqCst);
+            self.readers[epoch].fetch_add(1, Ordering::SeqCst);
+
+            // A store may have switched epochs in the meantime, and not be waiting for us anymore
+            if self.epoch.load(Ordering::SeqCst) == epoch {
+                return epoch;
+            }
+
+            self.readers[epoch].fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+}
+
+impl<'a, P, R> Drop for SharedSelfie<'a, P, R>
+where
+    P: 'a,
+    R: RefFamily,
+{
+    fn drop(&mut self) {
+        // SAFETY: current comes from Arc::into_raw, and no load can be in progress anymore
+        drop(unsafe { Arc::from_raw(*self.current.get_mut()) });
+    }
+}
+
+impl<'a, P, R> From<Selfie<'a, P, R>> for SharedSelfie<'a, P, R>
+where
+    P: 'a,
+    R: RefFamily,
+{
+    #[inline]
+    fn from(selfie: Selfie<'a, P, R>) -> Self {
+        Self::new(selfie)
+    }
+}
+
+impl<'a, P, R> Debug for SharedSelfie<'a, P, R>
+where
+    P: 'a,
+    R: RefFamily,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SharedSelfie").finish_non_exhaustive()
+    }
+}
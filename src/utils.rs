@@ -1,6 +1,6 @@
 #![allow(unsafe_code)] // This module contains slightly-less-unsafe (but still unsafe) helpers.
 
-use crate::refs::RefType;
+use crate::refs::RefFamily;
 use core::pin::Pin;
 
 #[inline]
@@ -18,15 +18,15 @@ pub unsafe fn detach_lifetime_mut<'this, T: ?Sized>(pin: Pin<&mut T>) -> Pin<&'t
 }
 
 #[inline]
-pub unsafe fn downcast_ref<'s, 'owned: 's, R: for<'this> RefType<'this> + ?Sized>(
-    referential: &'s <R as RefType<'owned>>::Ref,
-) -> &'s <R as RefType<'s>>::Ref {
+pub unsafe fn downcast_ref<'s, 'owned: 's, R: RefFamily + ?Sized>(
+    referential: &'s <R as RefFamily>::Ref<'owned>,
+) -> &'s <R as RefFamily>::Ref<'s> {
     ::core::mem::transmute(referential)
 }
 
 #[inline]
-pub unsafe fn downcast_mut<'s, 'owned: 's, R: for<'this> RefType<'this> + ?Sized>(
-    referential: &'s mut <R as RefType<'owned>>::Ref,
-) -> &'s mut <R as RefType<'s>>::Ref {
+pub unsafe fn downcast_mut<'s, 'owned: 's, R: RefFamily + ?Sized>(
+    referential: &'s mut <R as RefFamily>::Ref<'owned>,
+) -> &'s mut <R as RefFamily>::Ref<'s> {
     ::core::mem::transmute(referential)
 }
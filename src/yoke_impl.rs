@@ -0,0 +1,94 @@
+//! Conversions between [`Selfie`] and [`yoke`]'s [`Yoke`].
+//!
+//! These are only available with the `yoke` feature enabled, which requires Rust 1.82 or newer.
+
+#![allow(unsafe_code)] // Moving a yokeable in and out of its Yoke requires Yokeable::make
+
+use crate::refs::{Ref, RefFamily};
+use crate::Selfie;
+use core::pin::Pin;
+use stable_deref_trait::StableDeref;
+use yoke::{Yoke, Yokeable};
+
+/// Converts a [`Selfie`] into a [`Yoke`] using the same owned pointer as its cart.
+///
+/// The yokeable type `Y` is the `'static` version of the referential type of `R`.
+///
+/// # Example
+///
+/// ```
+/// use std::pin::Pin;
+/// use selfie::refs::Ref;
+/// use selfie::Selfie;
+/// use yoke::Yoke;
+///
+/// let data = Pin::new("Hello, world!".to_owned());
+/// let selfie: Selfie<String, Ref<str>> = Selfie::new(data, |s| &s[0..5]);
+///
+/// let yoke: Yoke<&'static str, String> = selfie.into();
+/// assert_eq!("Hello", *yoke.get());
+/// ```
+impl<'a, P, R, Y> From<Selfie<'a, P, R>> for Yoke<Y, P>
+where
+    P: StableDeref + 'a,
+    P::Target: Unpin + 'static,
+    R: RefFamily,
+    Y: for<'y> Yokeable<'y, Output = <R as RefFamily>::Ref<'y>>,
+{
+    fn from(selfie: Selfie<'a, P, R>) -> Self {
+        let mut yokeable = None;
+
+        let selfie: Selfie<'a, P, Ref<()>> = selfie.map(|referential, _| {
+            // SAFETY: the yokeable is only used to be attached back to the same owned data below,
+            // which is neither dropped nor moved in the meantime.
+            yokeable = Some(unsafe { Y::make(referential) });
+            &()
+        });
+
+        let yokeable = yokeable.expect("Selfie::map always calls its mapper");
+        let cart = Pin::into_inner(selfie.into_owned());
+
+        Yoke::attach_to_cart(cart, move |_| yokeable.transform_owned())
+    }
+}
+
+/// Converts a [`Yoke`] into a [`Selfie`] using the same cart as its owned pointer.
+///
+/// The yokeable type `Y` is the `'static` version of the referential type of `R`.
+///
+/// # Example
+///
+/// ```
+/// use selfie::refs::Ref;
+/// use selfie::Selfie;
+/// use yoke::Yoke;
+///
+/// let yoke: Yoke<&'static str, String> =
+///     Yoke::attach_to_cart("Hello, world!".to_owned(), |s| &s[0..5]);
+///
+/// let selfie: Selfie<String, Ref<str>> = yoke.into();
+/// assert_eq!("Hello", selfie.with_referential(|r| *r));
+/// assert_eq!("Hello, world!", selfie.owned());
+/// ```
+impl<'a, P, R, Y> From<Yoke<Y, P>> for Selfie<'a, P, R>
+where
+    P: StableDeref + 'a,
+    P::Target: Unpin + 'a,
+    R: RefFamily,
+    Y: for<'y> Yokeable<'y, Output = <R as RefFamily>::Ref<'y>>,
+{
+    fn from(yoke: Yoke<Y, P>) -> Self {
+        let mut yokeable = None;
+
+        let yoke: Yoke<(), P> = yoke.map_project(|referential, _| {
+            // SAFETY: the yokeable is only used to be attached back to the same cart below,
+            // which is neither dropped nor moved in the meantime.
+            yokeable = Some(unsafe { Y::make(referential) });
+        });
+
+        let yokeable = yokeable.expect("Yoke::map_project always calls its mapper");
+        let owned = Pin::new(yoke.into_backing_cart());
+
+        Selfie::new(owned, move |_| yokeable.transform_owned())
+    }
+}
@@ -0,0 +1,111 @@
+#![cfg(feature = "alloc-guard")]
+
+use selfie::alloc_guard::{assert_no_alloc, count_allocator_calls, CountingAllocator};
+use selfie::refs::Ref;
+use selfie::{Selfie, SelfieError};
+use std::pin::Pin;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator::system();
+
+#[test]
+pub fn allocator_calls_are_counted() {
+    let (_, calls) = count_allocator_calls(|| drop(Box::new(42)));
+    assert_eq!(2, calls);
+}
+
+#[test]
+#[should_panic]
+pub fn assert_no_alloc_panics_on_allocation() {
+    assert_no_alloc(|| Box::new(42));
+}
+
+#[test]
+pub fn new_does_not_allocate() {
+    let data = "Hello, world!".to_owned();
+
+    let selfie: Selfie<&String, Ref<str>> =
+        assert_no_alloc(|| Selfie::new(Pin::new(&data), |s| &s[0..5]));
+    assert_eq!("Hello", selfie.with_referential(|r| *r));
+}
+
+#[test]
+pub fn try_new_does_not_allocate() {
+    let data = "Hello, world!".to_owned();
+
+    assert_no_alloc(|| {
+        let selfie: Result<Selfie<&String, Ref<str>>, SelfieError<&String, ()>> =
+            Selfie::try_new(Pin::new(&data), |s| Ok(&s[0..5]));
+        assert!(selfie.is_ok());
+
+        let selfie: Result<Selfie<&String, Ref<str>>, SelfieError<&String, ()>> =
+            Selfie::try_new(Pin::new(&data), |_| Err(()));
+        assert!(selfie.is_err());
+    });
+}
+
+#[test]
+pub fn with_referential_does_not_allocate() {
+    let data = "Hello, world!".to_owned();
+    let selfie: Selfie<&String, Ref<str>> = Selfie::new(Pin::new(&data), |s| &s[0..5]);
+
+    let hello = assert_no_alloc(|| selfie.with_referential(|r| *r));
+    assert_eq!("Hello", hello);
+}
+
+#[test]
+pub fn map_does_not_allocate() {
+    let data = "Hello, world!".to_owned();
+    let selfie: Selfie<&String, Ref<str>> = Selfie::new(Pin::new(&data), |s| &s[0..5]);
+
+    let selfie = assert_no_alloc(|| selfie.map::<Ref<str>, _>(|r, _| &r[3..]));
+    assert_eq!("lo", selfie.with_referential(|r| *r));
+}
+
+#[test]
+pub fn try_map_does_not_allocate() {
+    let data = "Hello, world!".to_owned();
+    let selfie: Selfie<&String, Ref<str>> = Selfie::new(Pin::new(&data), |s| &s[0..5]);
+
+    let selfie = assert_no_alloc(|| {
+        selfie
+            .try_map::<Ref<str>, (), _>(|r, _| Ok(&r[3..]))
+            .unwrap()
+    });
+    assert_eq!("lo", selfie.with_referential(|r| *r));
+
+    let error = assert_no_alloc(|| selfie.try_map::<Ref<str>, (), _>(|_, _| Err(())));
+    assert!(error.is_err());
+}
+
+#[test]
+pub fn into_owned_does_not_allocate() {
+    let data = "Hello, world!".to_owned();
+    let selfie: Selfie<&String, Ref<str>> = Selfie::new(Pin::new(&data), |s| &s[0..5]);
+
+    let owned = assert_no_alloc(|| selfie.into_owned());
+    assert_eq!("Hello, world!", owned.as_str());
+}
+
+#[test]
+pub fn rt_handoff_does_not_allocate() {
+    let (mut producer, mut consumer) = selfie::rt::triple_buffer::<Selfie<&String, Ref<str>>>();
+    let first = "Hello, world!".to_owned();
+    let second = "Goodbye, world!".to_owned();
+
+    assert_no_alloc(|| {
+        assert!(producer
+            .publish(Selfie::new(Pin::new(&first), |s| &s[0..5]))
+            .is_none());
+        assert!(consumer.update());
+        assert_eq!(Some("Hello"), consumer.with_referential(|r| *r));
+
+        assert!(producer
+            .publish(Selfie::new(Pin::new(&second), |s| &s[0..7]))
+            .is_none());
+        assert!(consumer.update());
+        assert_eq!(Some("Goodbye"), consumer.with_referential(|r| *r));
+
+        assert!(producer.collect().is_some());
+    });
+}
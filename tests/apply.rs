@@ -0,0 +1,73 @@
+use selfie::refs::{Apply, Mut, Ref, TypeCtor};
+use selfie::{Selfie, SelfieMut};
+use std::pin::Pin;
+
+struct Pair<T>(T, T);
+
+struct PairCtor;
+
+impl<T> TypeCtor<T> for PairCtor {
+    type Applied = Pair<T>;
+}
+
+struct VecCtor;
+
+impl<T> TypeCtor<T> for VecCtor {
+    type Applied = Vec<T>;
+}
+
+#[test]
+pub fn apply() {
+    let data = Pin::new("Hello, world!".to_owned());
+    let selfie: Selfie<String, Apply<PairCtor, Ref<str>>> =
+        Selfie::new(data, |s| Pair(&s[0..5], &s[7..]));
+
+    assert_eq!("Hello", selfie.with_referential(|p| p.0));
+    assert_eq!("world!", selfie.with_referential(|p| p.1));
+
+    let selfie = Box::new(selfie);
+
+    assert_eq!("Hello", selfie.with_referential(|p| p.0));
+    assert_eq!("world!", selfie.with_referential(|p| p.1));
+}
+
+#[test]
+pub fn nested_apply() {
+    let data = Pin::new("Hello, world! Goodbye, world!".to_owned());
+
+    #[allow(clippy::type_complexity)]
+    let selfie: Selfie<String, Apply<VecCtor, Apply<PairCtor, Ref<str>>>> =
+        Selfie::new(data, |s| {
+            s.split("! ")
+                .map(|s| {
+                    let (a, b) = s.split_once(", ").unwrap();
+                    Pair(a, b)
+                })
+                .collect()
+        });
+
+    selfie.with_referential(|pairs| {
+        assert_eq!(2, pairs.len());
+        assert_eq!("Hello", pairs[0].0);
+        assert_eq!("world", pairs[0].1);
+        assert_eq!("Goodbye", pairs[1].0);
+        assert_eq!("world!", pairs[1].1);
+    });
+}
+
+#[test]
+// Miri reports moving a Box owner as invalidating the referential, like in cascading_mut
+#[cfg_attr(miri, ignore)]
+pub fn apply_mut() {
+    let data = Box::pin((1, 2));
+
+    #[allow(clippy::type_complexity)]
+    let mut selfie: SelfieMut<Box<(i32, i32)>, Apply<PairCtor, Mut<i32>>> =
+        SelfieMut::new(data, |values| {
+            let (a, b) = Pin::into_inner(values);
+            Pair(a, b)
+        });
+
+    selfie.with_referential_mut(|p| std::mem::swap(p.0, p.1));
+    assert_eq!((2, 1), *selfie.into_owned());
+}
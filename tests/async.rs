@@ -0,0 +1,134 @@
+use selfie::refs::Ref;
+use selfie::{Selfie, SelfieError};
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// A tiny executor, running the given future to completion on the current thread.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = Box::pin(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+/// A future which is pending once, waking itself up immediately.
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            return Poll::Ready(());
+        }
+
+        self.0 = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+async fn decode(input: &str) -> &str {
+    YieldNow(false).await;
+    &input[0..5]
+}
+
+async fn try_decode(input: &str) -> Result<&str, usize> {
+    YieldNow(false).await;
+    input.get(0..5).ok_or(input.len())
+}
+
+#[test]
+fn new_async() {
+    let data: Pin<String> = Pin::new("Hello, world!".to_owned());
+    let selfie: Selfie<String, Ref<str>> = block_on(Selfie::new_async(data, decode));
+
+    assert_eq!("Hello", selfie.with_referential(|r| *r));
+    assert_eq!("Hello, world!", selfie.owned());
+}
+
+#[test]
+fn try_new_async() {
+    let data: Pin<String> = Pin::new("Hello, world!".to_owned());
+    let selfie: Result<Selfie<String, Ref<str>>, SelfieError<String, usize>> =
+        block_on(Selfie::try_new_async(data, try_decode));
+
+    assert_eq!("Hello", selfie.unwrap().with_referential(|r| *r));
+}
+
+#[test]
+fn try_new_async_err() {
+    let data: Pin<String> = Pin::new("Hey".to_owned());
+    let selfie: Result<Selfie<String, Ref<str>>, SelfieError<String, usize>> =
+        block_on(Selfie::try_new_async(data, try_decode));
+
+    let error = selfie.unwrap_err();
+    assert_eq!(3, error.error);
+    assert_eq!("Hey", &*error.owned);
+}
+
+type Log = Rc<RefCell<Vec<&'static str>>>;
+
+struct Owner {
+    log: Log,
+}
+
+impl Drop for Owner {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push("owner");
+    }
+}
+
+struct Borrower<'a> {
+    owner: &'a Owner,
+}
+
+impl Drop for Borrower<'_> {
+    fn drop(&mut self) {
+        self.owner.log.borrow_mut().push("borrower");
+    }
+}
+
+async fn never_ready(owner: &Owner) -> &Owner {
+    let borrower = Borrower { owner };
+    std::future::pending::<()>().await;
+    borrower.owner
+}
+
+#[test]
+fn new_async_cancelled() {
+    let log = Log::default();
+    let owner = Box::pin(Owner { log: log.clone() });
+
+    let mut future = Box::pin(Selfie::<Box<Owner>, Ref<Owner>>::new_async(
+        owner,
+        never_ready,
+    ));
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+
+    assert!(future.as_mut().poll(&mut context).is_pending());
+    assert!(log.borrow().is_empty());
+
+    drop(future);
+    assert_eq!(&["borrower", "owner"], log.borrow().as_slice());
+}
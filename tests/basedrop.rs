@@ -0,0 +1,133 @@
+#![cfg(feature = "basedrop")]
+
+use basedrop::Collector;
+use selfie::basedrop::{OwnedOwner, SharedOwner};
+use selfie::refs::{Mut, Ref};
+use selfie::{Selfie, SelfieMut};
+use std::pin::Pin;
+use std::thread;
+
+/// Drops the given value on a separate (realtime) thread.
+fn drop_on_realtime_thread<T: Send + 'static>(value: T) {
+    thread::spawn(move || drop(value)).join().unwrap()
+}
+
+#[test]
+pub fn owned_drop_is_deferred() {
+    let mut collector = Collector::new();
+    let data = OwnedOwner::new(&collector.handle(), "Hello, world!".to_owned());
+    let selfie: Selfie<OwnedOwner<String>, Ref<str>> = Selfie::new(Pin::new(data), |s| &s[0..5]);
+
+    assert_eq!("Hello", selfie.with_referential(|r| *r));
+
+    drop_on_realtime_thread(selfie);
+    assert_eq!(1, collector.alloc_count());
+
+    collector.collect();
+    assert_eq!(0, collector.alloc_count());
+    assert!(collector.try_cleanup().is_ok());
+}
+
+#[test]
+pub fn owned_mut_drop_is_deferred() {
+    let mut collector = Collector::new();
+    let data = OwnedOwner::new(&collector.handle(), vec![0u8; 16]);
+    let mut selfie: SelfieMut<OwnedOwner<Vec<u8>>, Mut<[u8]>> =
+        SelfieMut::new(Pin::new(data), |v| &mut Pin::into_inner(v)[4..]);
+
+    selfie.with_referential_mut(|r| r.fill(42));
+
+    drop_on_realtime_thread(selfie);
+    assert_eq!(1, collector.alloc_count());
+
+    collector.collect();
+    assert_eq!(0, collector.alloc_count());
+    assert!(collector.try_cleanup().is_ok());
+}
+
+#[test]
+pub fn shared_drop_is_deferred() {
+    let mut collector = Collector::new();
+    let data = SharedOwner::new(&collector.handle(), "Hello, world!".to_owned());
+    let selfie: Selfie<SharedOwner<String>, Ref<str>> = Selfie::new(Pin::new(data), |s| &s[0..5]);
+    let second_selfie = selfie.map_cloned::<Ref<str>, _>(|s, _| &s[3..]);
+
+    drop_on_realtime_thread(selfie);
+    collector.collect();
+    assert_eq!(1, collector.alloc_count());
+    assert_eq!("lo", second_selfie.with_referential(|r| *r));
+
+    drop_on_realtime_thread(second_selfie);
+    assert_eq!(1, collector.alloc_count());
+
+    collector.collect();
+    assert_eq!(0, collector.alloc_count());
+    assert!(collector.try_cleanup().is_ok());
+}
+
+/// Checks that dropping on the realtime thread does not allocate nor deallocate anything.
+#[cfg(feature = "alloc-guard")]
+mod alloc_free {
+    use super::*;
+    use selfie::alloc_guard::{count_allocator_calls, CountingAllocator};
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator::system();
+
+    /// Drops the given value on a separate (realtime) thread, and returns the number of allocator
+    /// calls the drop made.
+    fn count_realtime_drop<T: Send + 'static>(value: T) -> usize {
+        thread::spawn(move || count_allocator_calls(|| drop(value)).1)
+            .join()
+            .unwrap()
+    }
+
+    #[test]
+    pub fn owned_drop_does_not_allocate() {
+        let mut collector = Collector::new();
+        let data = OwnedOwner::new(&collector.handle(), "Hello, world!".to_owned());
+        let selfie: Selfie<OwnedOwner<String>, Ref<str>> =
+            Selfie::new(Pin::new(data), |s| &s[0..5]);
+
+        assert_eq!(0, count_realtime_drop(selfie));
+        collector.collect();
+        assert!(collector.try_cleanup().is_ok());
+    }
+
+    #[test]
+    pub fn owned_mut_drop_does_not_allocate() {
+        let mut collector = Collector::new();
+        let data = OwnedOwner::new(&collector.handle(), vec![0u8; 16]);
+        let selfie: SelfieMut<OwnedOwner<Vec<u8>>, Mut<[u8]>> =
+            SelfieMut::new(Pin::new(data), |v| &mut Pin::into_inner(v)[4..]);
+
+        assert_eq!(0, count_realtime_drop(selfie));
+        collector.collect();
+        assert!(collector.try_cleanup().is_ok());
+    }
+
+    #[test]
+    pub fn shared_drop_does_not_allocate() {
+        let mut collector = Collector::new();
+        let data = SharedOwner::new(&collector.handle(), "Hello, world!".to_owned());
+        let selfie: Selfie<SharedOwner<String>, Ref<str>> =
+            Selfie::new(Pin::new(data), |s| &s[0..5]);
+        let second_selfie = selfie.map_cloned::<Ref<str>, _>(|s, _| &s[3..]);
+
+        assert_eq!(0, count_realtime_drop(selfie));
+        assert_eq!(0, count_realtime_drop(second_selfie));
+        collector.collect();
+        assert!(collector.try_cleanup().is_ok());
+    }
+
+    #[test]
+    // Dropping a Selfie passed by value is the known by-value protector issue under Miri
+    #[cfg_attr(miri, ignore)]
+    pub fn boxed_drop_is_not_deferred() {
+        // Makes sure allocator calls are actually counted
+        let data = Pin::new("Hello, world!".to_owned());
+        let selfie: Selfie<String, Ref<str>> = Selfie::new(data, |s| &s[0..5]);
+
+        assert_eq!(1, count_realtime_drop(selfie));
+    }
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "bytes")]
+
+use bytes::Bytes;
+use selfie::bytes::BytesOwner;
+use selfie::refs::Ref;
+use selfie::Selfie;
+use std::ops::Bound;
+use std::pin::Pin;
+
+#[test]
+pub fn bytes_owner() {
+    let bytes = Bytes::from(b"Hello, world!".to_vec());
+    let selfie: Selfie<BytesOwner, Ref<[u8]>> = Selfie::new(Pin::new(bytes.into()), |b| &b[7..]);
+
+    assert_eq!(b"world!", selfie.with_referential(|r| *r));
+
+    let selfie = Box::new(selfie);
+    assert_eq!(b"world!", selfie.with_referential(|r| *r));
+    assert_eq!(b"Hello, world!", selfie.owned());
+}
+
+#[test]
+pub fn slices_share_buffer() {
+    let bytes = Bytes::from(b"Hello, world!".to_vec());
+    let ptr = bytes.as_ptr();
+
+    let selfie = Selfie::from_bytes(bytes);
+    let (hello, world) = selfie.split_at(5);
+    drop(selfie);
+
+    assert_eq!(ptr, hello.owned().as_ptr());
+    assert_eq!(ptr, world.owned().as_ptr());
+
+    let world = world.slice(2..=6);
+    drop(hello);
+
+    assert_eq!(b"world", world.with_referential(|r| *r));
+    assert_eq!(ptr, world.owned().as_ptr());
+    assert_eq!(b"", world.slice(5..).with_referential(|r| *r));
+
+    let bytes = Pin::into_inner(world.into_owned()).into_inner();
+    assert_eq!(&b"Hello, world!"[..], bytes);
+}
+
+#[test]
+#[should_panic]
+pub fn slice_out_of_bounds() {
+    let selfie = Selfie::from_bytes(Bytes::from_static(b"Hello"));
+    selfie.slice(..6);
+}
+
+#[test]
+#[should_panic]
+pub fn slice_inclusive_end_overflow() {
+    let selfie = Selfie::from_bytes(Bytes::from_static(b"Hello"));
+    selfie.slice(..=usize::MAX);
+}
+
+#[test]
+#[should_panic]
+pub fn slice_excluded_start_overflow() {
+    let selfie = Selfie::from_bytes(Bytes::from_static(b"Hello"));
+    selfie.slice((Bound::Excluded(usize::MAX), Bound::Unbounded));
+}
@@ -1,4 +1,4 @@
-use selfie::refs::{Mut, Ref, SelfieRef, SelfieRefMut};
+use selfie::refs::{Mut, Ref, RefFamily, SelfieRef, SelfieRefMut};
 use selfie::{Selfie, SelfieMut};
 use std::pin::Pin;
 
@@ -85,3 +85,86 @@ pub fn cascading_mut() {
     let my_str = selfie.into_owned();
     assert_eq!(b"Hella, world!", &my_str[..]);
 }
+
+#[test]
+pub fn with_innermost() {
+    let my_str = Pin::new("Hello, world!".to_owned());
+
+    #[allow(clippy::type_complexity)]
+    let data: Selfie<String, SelfieRef<Ref<str>, SelfieRef<Ref<str>, Ref<str>>>> =
+        Selfie::new(my_str, |i| {
+            let substr = Pin::new(&i[0..5]);
+            Selfie::new(substr, |i| {
+                let substr = Pin::new(&i[1..]);
+                Selfie::new(substr, |i| &i[2..])
+            })
+        });
+
+    assert_eq!("lo", data.with_innermost(|r| *r));
+
+    let data = Box::new(data);
+    assert_eq!("lo", data.with_innermost(|r| *r));
+}
+
+#[test]
+pub fn flatten() {
+    let my_str = Pin::new("Hello, world!".to_owned());
+    let data: Selfie<String, SelfieRef<Ref<str>, Ref<str>>> = Selfie::new(my_str, |i| {
+        let substr = Pin::new(&i[0..5]);
+        Selfie::new(substr, |i| &i[3..])
+    });
+
+    let flat = data.flatten();
+    assert_eq!("Hello, world!", flat.owned());
+    assert_eq!("lo", flat.with_referential(|r| *r));
+
+    let data: Selfie<String, SelfieRef<Ref<str>, Ref<str>>> = flat.into();
+    data.with_referential(|r1| assert_eq!("Hello", r1.owned()));
+
+    let my_str = data.flatten().into_owned();
+    assert_eq!("Hello, world!", my_str.as_ref().get_ref());
+}
+
+#[test]
+pub fn then_chain() {
+    let my_str = Pin::new("Hello, world!".to_owned());
+    let data: Selfie<String, Ref<str>> = Selfie::new(my_str, |i| &i[0..5]);
+
+    #[allow(clippy::type_complexity)]
+    let data: Selfie<String, SelfieRef<Ref<str>, SelfieRef<Ref<str>, Ref<[u8]>>>> = data
+        .then::<Ref<str>, _>(|i| &i[1..])
+        .then::<Ref<[u8]>, _>(|i| &i.as_bytes()[2..]);
+
+    assert_eq!("Hello, world!", data.owned());
+    data.with_referential(|r1| {
+        assert_eq!("Hello", r1.owned());
+        r1.with_referential(|r2| assert_eq!("ello", r2.owned()))
+    });
+    assert_eq!(b"lo", data.with_innermost(|r| *r));
+
+    // Moving the Selfie has no consequence
+    let data = Box::new(data);
+    assert_eq!(b"lo", data.flatten().with_referential(|r| *r));
+}
+
+#[test]
+pub fn ref_family_stand_ins() {
+    struct Substr;
+
+    impl RefFamily for Substr {
+        type Ref<'a> = &'a str;
+    }
+
+    let my_str = Pin::new("Hello, world!".to_owned());
+    // A stand-in only implementing RefFamily can be the inner referential, but not the owned pointer
+    let selfie: Selfie<String, SelfieRef<Ref<str>, Substr>> = Selfie::new(my_str, |i| {
+        let substr = Pin::new(&i[0..5]);
+        Selfie::new(substr, |i| &i[3..])
+    });
+
+    assert_eq!("Hello, world!", selfie.owned());
+    selfie.with_referential(|r1| {
+        assert_eq!("Hello", r1.owned());
+        assert_eq!("lo", r1.with_referential(|r| *r));
+    });
+}
@@ -0,0 +1,152 @@
+use selfie::refs::{Owned, Ref, RefType, SelfieRef};
+use selfie::{Chain, Selfie};
+use std::cell::RefCell;
+use std::pin::Pin;
+use std::rc::Rc;
+
+fn decompress(compressed: &[u8]) -> Vec<u8> {
+    compressed
+        .chunks(2)
+        .flat_map(|chunk| std::iter::repeat(chunk[1]).take(chunk[0] as usize))
+        .collect()
+}
+
+#[test]
+pub fn simple_chain() {
+    let compressed = Pin::new(vec![3, b'a', 2, b'b', 1, b'c']);
+
+    let chain: Chain<Vec<u8>, Vec<u8>, Ref<[u8]>> =
+        Chain::new(compressed, |c| Pin::new(decompress(&c[..4])), |d| &d[2..]);
+
+    assert_eq!(b"abb", chain.with_referential(|r| *r));
+    assert_eq!(b"aaabb", chain.intermediate());
+
+    // Moving the Chain has no consequence
+    let mut chain = Box::new(chain);
+    assert_eq!(b"abb", chain.with_referential(|r| *r));
+
+    chain.with_referential_mut(|r| *r = &r[1..]);
+    assert_eq!(b"bb", chain.with_referential(|r| *r));
+}
+
+#[test]
+// into_owned drops the intermediate owner while the referential borrowing from it is still part of
+// its by-value self argument, which Miri reports as UB. Avoiding this requires a MaybeDangling-like
+// wrapper for the referential, which Rust does not provide yet.
+#[cfg_attr(miri, ignore)]
+pub fn chain_into_owned() {
+    let compressed = Pin::new(vec![3, b'a', 2, b'b', 1, b'c']);
+
+    let chain: Chain<Vec<u8>, Vec<u8>, Ref<[u8]>> =
+        Chain::new(compressed, |c| Pin::new(decompress(&c[..4])), |d| &d[2..]);
+
+    let compressed = chain.into_owned();
+    assert_eq!(6, compressed.len());
+}
+
+#[test]
+pub fn chain_into_selfie() {
+    let data = Pin::new("Hello, world!".to_owned());
+    let chain: Chain<String, String, Ref<str>> =
+        Chain::new(data, |s| Pin::new(s.to_uppercase()), |s| &s[7..]);
+
+    let selfie: Selfie<String, SelfieRef<Owned<String>, Ref<str>>> = chain.into();
+    selfie.with_referential(|inner| {
+        assert_eq!("HELLO, WORLD!", inner.owned());
+        assert_eq!("WORLD!", inner.with_referential(|r| *r));
+    });
+
+    let chain: Chain<String, String, Ref<str>> = selfie.into();
+    assert_eq!("WORLD!", chain.with_referential(|r| *r));
+}
+
+#[test]
+pub fn try_new_chain() {
+    let data = Pin::new("Hello, world!".to_owned());
+    let chain: Result<Chain<String, String, Ref<str>>, _> = Chain::try_new(
+        data,
+        |s| Ok(Pin::new(s.to_uppercase())),
+        |s| s.get(7..).ok_or("Out of bounds"),
+    );
+    assert_eq!("WORLD!", chain.unwrap().with_referential(|r| *r));
+
+    let data = Pin::new("Hello, world!".to_owned());
+    let error =
+        Chain::<String, String, Ref<str>>::try_new(data, |_| Err("Intermediate error"), |s| Ok(s))
+            .unwrap_err();
+    assert_eq!("Intermediate error", error.error);
+    assert_eq!("Hello, world!", &*error.owned);
+
+    let data = Pin::new("Hello, world!".to_owned());
+    let error = Chain::<String, String, Ref<str>>::try_new(
+        data,
+        |s| Ok(Pin::new(s.to_uppercase())),
+        |s| s.get(42..).ok_or("Out of bounds"),
+    )
+    .unwrap_err();
+    assert_eq!("Out of bounds", error.error);
+    assert_eq!("Hello, world!", &*error.owned);
+}
+
+struct Recorder {
+    name: &'static str,
+    log: Rc<RefCell<Vec<&'static str>>>,
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(self.name);
+    }
+}
+
+struct Referential<'a> {
+    intermediate: &'a Recorder,
+}
+
+impl Drop for Referential<'_> {
+    fn drop(&mut self) {
+        // Reading from the intermediate owner here must be valid
+        let log = self.intermediate.log.clone();
+        log.borrow_mut().push("referential");
+    }
+}
+
+struct ReferentialRef;
+
+impl<'a> RefType<'a> for ReferentialRef {
+    type Ref = Referential<'a>;
+}
+
+#[test]
+pub fn chain_drop_order() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    // This test runs under Miri, so it uses Rc owners (moving a Box invalidates references to its
+    // contents), and drops the chain in place at the end of this scope rather than passing it by
+    // value to drop()
+    {
+        let owned = Rc::pin(Recorder {
+            name: "first owner",
+            log: log.clone(),
+        });
+
+        let chain: Chain<Rc<Recorder>, Rc<Recorder>, ReferentialRef> = Chain::new(
+            owned,
+            |first| {
+                Rc::pin(Recorder {
+                    name: "second owner",
+                    log: first.log.clone(),
+                })
+            },
+            |intermediate| Referential { intermediate },
+        );
+
+        assert_eq!("second owner", chain.intermediate().name);
+        assert!(log.borrow().is_empty());
+    }
+
+    assert_eq!(
+        vec!["referential", "second owner", "first owner"],
+        *log.borrow()
+    );
+}
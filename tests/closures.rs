@@ -0,0 +1,63 @@
+use selfie::refs::{BoxFn, SendBoxFn};
+use selfie::{Selfie, SelfieMut};
+use std::pin::Pin;
+use std::thread;
+
+#[test]
+pub fn boxed_closure() {
+    let data = Pin::new("Hello, world!".to_owned());
+    let mut selfie: Selfie<String, BoxFn<usize, usize>> = Selfie::new(data, |s| {
+        Box::new(move |start| s[start..].len()) as Box<dyn FnMut(_) -> _>
+    });
+
+    assert_eq!(13, selfie.call(0));
+    assert_eq!(6, selfie.call(7));
+
+    let mut selfie = Box::new(selfie);
+
+    assert_eq!(6, selfie.call(7));
+    assert_eq!("Hello, world!", selfie.owned());
+}
+
+#[test]
+// Miri reports moving a Box owner as invalidating the referential, like in cascading_mut
+#[cfg_attr(miri, ignore)]
+pub fn boxed_closure_mut() {
+    let data = Box::pin([0.0f32; 4]);
+    let mut selfie: SelfieMut<Box<[f32; 4]>, BoxFn<f32, f32>> = SelfieMut::new(data, |buf| {
+        let buf = Pin::into_inner(buf);
+        let process = move |gain| {
+            buf.iter_mut().for_each(|s| *s += gain);
+            buf.iter().sum()
+        };
+
+        Box::new(process) as Box<dyn FnMut(_) -> _>
+    });
+
+    assert_eq!(4.0, selfie.call(1.0));
+    assert_eq!(12.0, selfie.call(2.0));
+
+    let data = selfie.into_owned();
+    assert_eq!([3.0; 4], *data);
+}
+
+#[test]
+// Miri reports moving a Box owner as invalidating the referential, like in cascading_mut
+#[cfg_attr(miri, ignore)]
+pub fn send_closure_to_thread() {
+    let data = Box::pin([0.0f32; 4]);
+    let mut selfie: SelfieMut<Box<[f32; 4]>, SendBoxFn<f32, ()>> = SelfieMut::new(data, |buf| {
+        let buf = Pin::into_inner(buf);
+        Box::new(move |gain| buf.iter_mut().for_each(|s| *s += gain)) as Box<dyn FnMut(_) + Send>
+    });
+
+    let selfie = thread::spawn(move || {
+        selfie.call(0.5);
+        selfie.call(0.25);
+        selfie
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!([0.75; 4], *selfie.into_owned());
+}
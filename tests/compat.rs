@@ -0,0 +1,64 @@
+#![cfg(feature = "compat")]
+
+use selfie::compat::SelfCell;
+use selfie::refs::{Ref, RefType};
+use selfie::Selfie;
+use std::pin::Pin;
+use std::rc::Rc;
+
+#[test]
+pub fn self_cell() {
+    let cell: SelfCell<String, Ref<str>> = SelfCell::new("Hello, world!".to_owned(), |s| &s[0..5]);
+
+    assert_eq!("Hello, world!", cell.borrow_owner());
+    assert_eq!("Hello", *cell.borrow_dependent());
+
+    let mut cell = Box::new(cell);
+
+    cell.with_dependent_mut(|_, dependent| *dependent = &dependent[3..]);
+    assert_eq!("lo", *cell.borrow_dependent());
+    cell.with_dependent(|owner, dependent| assert_eq!(owner.len() - 11, dependent.len()));
+
+    assert_eq!("Hello, world!", cell.into_owner());
+}
+
+#[test]
+pub fn try_new() {
+    let cell: Result<SelfCell<Rc<str>, Ref<str>>, usize> =
+        SelfCell::try_new("Hello".into(), |s| s.get(0..10).ok_or(s.len()));
+    assert_eq!(5, cell.err().unwrap());
+
+    let owner: Rc<str> = "Hello".into();
+
+    #[allow(clippy::type_complexity)]
+    let cell: Result<SelfCell<Rc<str>, Ref<str>>, (Rc<str>, ())> =
+        SelfCell::try_new_or_recover(owner.clone(), |s| s.get(0..10).ok_or(()));
+    let (recovered, ()) = cell.err().unwrap();
+    assert!(Rc::ptr_eq(&owner, &recovered));
+
+    let cell: Result<SelfCell<Rc<str>, Ref<str>>, ()> =
+        SelfCell::try_new(owner, |s| s.get(1..).ok_or(()));
+    assert_eq!("ello", *cell.unwrap().borrow_dependent());
+}
+
+struct Words<'a>(Vec<&'a str>);
+
+struct WordsRef;
+
+impl<'a> RefType<'a> for WordsRef {
+    type Ref = Words<'a>;
+}
+
+#[test]
+pub fn selfie_conversions() {
+    let data = Pin::new("Hello, world!".to_owned());
+    let selfie: Selfie<String, WordsRef> = Selfie::new(data, |s| Words(s.split(", ").collect()));
+
+    // Not covariant: only accessible through with_dependent
+    let mut cell: SelfCell<String, WordsRef> = selfie.into();
+    cell.with_dependent_mut(|_, words| words.0.push("again"));
+    cell.with_dependent(|_, words| assert_eq!(&["Hello", "world!", "again"], &words.0[..]));
+
+    let selfie: Selfie<String, WordsRef> = cell.into();
+    selfie.with_referential(|words| assert_eq!(3, words.0.len()));
+}
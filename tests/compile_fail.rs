@@ -5,4 +5,8 @@ fn ui() {
     t.compile_fail("tests/compile_fail/circular.rs");
     t.compile_fail("tests/compile_fail/clone.rs");
     t.compile_fail("tests/compile_fail/with_referential_mut_from_outer.rs");
+    t.compile_fail("tests/compile_fail/variance.rs");
+    t.compile_fail("tests/compile_fail/covariant.rs");
+    t.compile_fail("tests/compile_fail/zip_escape.rs");
+    t.compile_fail("tests/compile_fail/get_escape.rs");
 }
@@ -0,0 +1,20 @@
+use selfie::refs::{Covariant, RefType};
+use std::cell::Cell;
+
+// Covariant can only be implemented for referential types that actually are covariant.
+
+struct CellRef;
+
+impl<'a> RefType<'a> for CellRef {
+    type Ref = Cell<&'a str>;
+}
+
+impl Covariant for CellRef {
+    fn shorten_ref<'short, 'long: 'short>(
+        referential: &'short Cell<&'long str>,
+    ) -> &'short Cell<&'short str> {
+        referential
+    }
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use selfie::refs::Ref;
+use selfie::Selfie;
+use std::pin::Pin;
+
+fn main() {
+    let selfie: Selfie<String, Ref<str>> = Selfie::new(Pin::new("Hello".to_owned()), |s| s);
+
+    let hello: &str = selfie.get();
+    drop(selfie);
+
+    println!("{}", hello);
+}
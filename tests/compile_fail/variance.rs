@@ -0,0 +1,22 @@
+use selfie::refs::{Ref, RefType};
+use std::cell::{Cell, UnsafeCell};
+
+// Cells holding references are invariant: shortening their lifetime would allow storing a
+// shorter-lived reference into them.
+
+fn shorten_cell<'s, 'l: 's>(
+    r: <Cell<Ref<str>> as RefType<'l>>::Ref,
+) -> <Cell<Ref<str>> as RefType<'s>>::Ref {
+    r
+}
+
+fn shorten_unsafe_cell<'s, 'l: 's>(
+    r: <UnsafeCell<Ref<str>> as RefType<'l>>::Ref,
+) -> <UnsafeCell<Ref<str>> as RefType<'s>>::Ref {
+    r
+}
+
+fn main() {
+    shorten_cell(Cell::new("Hello"));
+    shorten_unsafe_cell(UnsafeCell::new("Hello"));
+}
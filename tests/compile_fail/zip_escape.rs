@@ -0,0 +1,18 @@
+use selfie::refs::Ref;
+use selfie::{Selfie, ZippedSelfie};
+use std::pin::Pin;
+
+fn main() {
+    let first: Selfie<String, Ref<str>> = Selfie::new(Pin::new("Hello".to_owned()), |s| s);
+    let second: Selfie<String, Ref<str>> = Selfie::new(Pin::new("world".to_owned()), |s| s);
+
+    let mut leaked = None;
+
+    let zipped: ZippedSelfie<String, String, Ref<str>> = first.zip(second, |first, second, _, _| {
+        leaked = Some(second);
+        first
+    });
+
+    drop(zipped);
+    println!("{}", leaked.unwrap());
+}
@@ -0,0 +1,103 @@
+use selfie::refs::Ref;
+use selfie::{Selfie, SelfieError};
+use std::pin::Pin;
+
+fn fail(data: &str, error: &'static str) -> SelfieError<String, &'static str> {
+    Selfie::<String, Ref<str>>::try_new(Pin::new(data.to_owned()), |_| Err(error)).unwrap_err()
+}
+
+#[test]
+pub fn into_parts() {
+    let (owned, error) = fail("Hello", "Oops").into_parts();
+    assert_eq!("Hello", &*owned);
+    assert_eq!("Oops", error);
+
+    assert_eq!("Hello", &*fail("Hello", "Oops").into_owned());
+    assert_eq!("Oops", fail("Hello", "Oops").into_error());
+}
+
+#[test]
+pub fn map_err() {
+    let error = fail("Hello", "Oops").map_err(|e| e.to_uppercase());
+    assert_eq!("OOPS", error.error);
+    assert_eq!("Hello", &*error.owned);
+}
+
+#[test]
+pub fn clone_and_eq() {
+    let error = fail("Hello", "Oops");
+    let cloned = error.clone();
+
+    assert_eq!(error, cloned);
+    assert_ne!(error, fail("Hello", "Other"));
+    assert_ne!(error, fail("Other", "Oops"));
+}
+
+#[test]
+#[cfg(selfie_core_error)]
+pub fn core_error() {
+    #[derive(Debug)]
+    struct ParseError;
+
+    impl core::fmt::Display for ParseError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str("Parse error")
+        }
+    }
+
+    impl core::error::Error for ParseError {}
+
+    fn source(error: &dyn core::error::Error) -> Option<String> {
+        error.source().map(|s| s.to_string())
+    }
+
+    let error: SelfieError<String, ParseError> =
+        Selfie::<String, Ref<str>>::try_new(Pin::new("Hello".to_owned()), |_| Err(ParseError))
+            .unwrap_err();
+
+    assert_eq!("Parse error", error.to_string());
+    assert_eq!(Some("Parse error".to_owned()), source(&error));
+}
+
+#[cfg(feature = "std")]
+mod std_conversions {
+    use super::*;
+    use std::error::Error;
+    use std::io;
+
+    fn parse(
+        data: Pin<String>,
+    ) -> Result<Selfie<'static, String, Ref<str>>, SelfieError<String, io::Error>> {
+        Selfie::try_new(data, |s| {
+            s.split(':')
+                .nth(1)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Missing separator"))
+        })
+    }
+
+    fn read_value(data: &str) -> io::Result<usize> {
+        let selfie = parse(Pin::new(data.to_owned()))?;
+        Ok(selfie.with_referential(|r| r.len()))
+    }
+
+    #[test]
+    pub fn into_io_error() {
+        assert_eq!(5, read_value("key:value").unwrap());
+
+        let error = read_value("value").unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, error.kind());
+        assert_eq!("Missing separator", error.to_string());
+    }
+
+    #[test]
+    pub fn into_boxed_error() {
+        fn read_boxed(data: &str) -> Result<usize, Box<dyn Error>> {
+            let selfie = parse(Pin::new(data.to_owned()))?;
+            Ok(selfie.with_referential(|r| r.len()))
+        }
+
+        let error = read_boxed("value").unwrap_err();
+        assert_eq!("Missing separator", error.to_string());
+        assert!(error.source().is_some());
+    }
+}
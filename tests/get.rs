@@ -0,0 +1,53 @@
+use selfie::refs::{Mut, Ref, SliceIter};
+use selfie::{Selfie, SelfieMut};
+use std::pin::Pin;
+
+fn first_word<'s>(selfie: &'s Selfie<'_, String, Ref<str>>) -> &'s str {
+    selfie.get().split(' ').next().unwrap()
+}
+
+#[test]
+fn get_flows_out() {
+    let data: Pin<String> = Pin::new("Hello, world!".to_owned());
+    let selfie: Selfie<String, Ref<str>> = Selfie::new(data, |s| &s[0..13]);
+
+    assert_eq!("Hello,", first_word(&selfie));
+}
+
+#[test]
+fn get_iterator() {
+    let data: Pin<Vec<u8>> = Pin::new(vec![1, 2, 3]);
+    let selfie: Selfie<Vec<u8>, SliceIter<u8>> = Selfie::new(data, |v| v.iter());
+
+    let sum: u8 = selfie.get().clone().sum();
+    assert_eq!(6, sum);
+}
+
+#[test]
+// Miri reports moving a Box owner as invalidating the referential, like in cascading_mut
+#[cfg_attr(miri, ignore)]
+fn selfie_mut_get() {
+    let data: Pin<Box<u32>> = Box::pin(42);
+    let selfie: SelfieMut<Box<u32>, Mut<u32>> = SelfieMut::new(data, |i| Pin::into_inner(i));
+
+    let value: &u32 = selfie.get();
+    assert_eq!(42, *value);
+}
+
+#[test]
+// Miri reports moving a Box owner as invalidating the referential, like in cascading_mut
+#[cfg_attr(miri, ignore)]
+fn selfie_mut_get_mut() {
+    let data: Pin<Box<[u32; 3]>> = Box::pin([1, 2, 3]);
+    let mut selfie: SelfieMut<Box<[u32; 3]>, Mut<[u32]>> =
+        SelfieMut::new(data, |a| &mut Pin::into_inner(a)[1..]);
+
+    {
+        let mut guard = selfie.get_mut();
+        guard[0] = 20;
+        guard[1] *= 10;
+        assert_eq!(&[20, 30], &*guard);
+    }
+
+    assert_eq!(&[1, 20, 30], &*selfie.into_owned());
+}
@@ -0,0 +1,57 @@
+use selfie::{Selfie, SelfieMut};
+use std::pin::Pin;
+use std::rc::Rc;
+
+struct Person {
+    name: String,
+    scores: Vec<u32>,
+}
+
+fn person() -> Pin<Rc<Person>> {
+    Rc::pin(Person {
+        name: "Ferris".to_owned(),
+        scores: vec![1, 2, 3],
+    })
+}
+
+#[test]
+fn new_ref() {
+    let selfie = Selfie::new_ref(person(), |p| &p.scores[1]);
+    assert_eq!(2, selfie.with_referential(|r| **r));
+}
+
+#[test]
+fn new_str() {
+    let selfie = Selfie::new_str(person(), |p| &p.name);
+    assert_eq!("Ferris", selfie.with_referential(|r| *r));
+}
+
+#[test]
+fn new_slice() {
+    let selfie = Selfie::new_slice(person(), |p| &p.scores);
+    assert_eq!(&[1, 2, 3], selfie.with_referential(|r| *r));
+}
+
+#[test]
+fn map_ref_chain() {
+    let selfie = Selfie::new_ref(person(), |p| p)
+        .map_ref(|p, _| p.name.as_str())
+        .map_ref(|name, _| &name[1..4]);
+
+    assert_eq!("err", selfie.with_referential(|r| *r));
+    assert_eq!("Ferris", selfie.owned().name);
+}
+
+#[test]
+fn new_mut_and_map() {
+    // Box owners are rejected by Miri once moved into a SelfieMut, Vec owners are not
+    let data = Pin::new(vec![1u32, 2, 3, 4]);
+    let selfie = SelfieMut::new_mut(data, |a| &mut Pin::into_inner(a)[1..]);
+
+    let mut selfie = selfie.map_mut(|slice, _| &mut slice[1..]);
+    selfie.with_referential_mut(|slice| slice[0] = 30);
+    let selfie = selfie.map_ref(|slice, _| &slice[..]);
+
+    assert_eq!(&[30, 4], selfie.with_referential(|r| *r));
+    assert_eq!(&[1, 2, 30, 4], &*selfie.into_owned());
+}
@@ -0,0 +1,69 @@
+use selfie::refs::{Lt, RefType};
+use selfie::{ForLt, Selfie, SelfieMut};
+use std::pin::Pin;
+
+struct Doc<'a> {
+    title: &'a str,
+    body: Vec<&'a str>,
+}
+
+fn parse(s: &str) -> Doc<'_> {
+    let mut lines = s.lines();
+    let title = lines.next().unwrap();
+
+    Doc {
+        title,
+        body: lines.collect(),
+    }
+}
+
+#[test]
+pub fn lt() {
+    let data = Pin::new("Title\nHello\nworld!".to_owned());
+    let selfie: Selfie<String, Lt<dyn for<'a> RefType<'a, Ref = Doc<'a>>>> =
+        Selfie::new(data, |s| parse(s));
+
+    assert_eq!("Title", selfie.with_referential(|doc| doc.title));
+    selfie.with_referential(|doc| assert_eq!(&["Hello", "world!"], &doc.body[..]));
+
+    let selfie = Box::new(selfie);
+
+    assert_eq!("Title", selfie.with_referential(|doc| doc.title));
+    selfie.with_referential(|doc| assert_eq!(&["Hello", "world!"], &doc.body[..]));
+}
+
+#[test]
+pub fn for_lt() {
+    let data = Pin::new("Title\nHello\nworld!".to_owned());
+    let mut selfie: Selfie<String, ForLt!(<'a> Doc<'a>)> = Selfie::new(data, |s| parse(s));
+
+    selfie.with_referential_mut(|doc| doc.body.retain(|line| line.starts_with('w')));
+    selfie.with_referential(|doc| assert_eq!(&["world!"], &doc.body[..]));
+    assert_eq!("Title\nHello\nworld!", selfie.owned());
+}
+
+struct Counters<'a> {
+    first: &'a mut u32,
+    second: &'a mut u32,
+}
+
+#[test]
+// Miri reports moving a Box owner as invalidating the referential, like in cascading_mut
+#[cfg_attr(miri, ignore)]
+pub fn for_lt_mut() {
+    let data = Box::pin((0, 0));
+
+    #[allow(clippy::type_complexity)]
+    let mut selfie: SelfieMut<Box<(u32, u32)>, ForLt!(<'a> Counters<'a>)> =
+        SelfieMut::new(data, |values| {
+            let (first, second) = Pin::into_inner(values);
+            Counters { first, second }
+        });
+
+    selfie.with_referential_mut(|c| {
+        *c.first += 1;
+        *c.second += 2;
+    });
+
+    assert_eq!((1, 2), *selfie.into_owned());
+}
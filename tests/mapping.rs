@@ -94,3 +94,26 @@ pub fn cloned_map() {
     drop(selfie);
     second_selfie.with_referential(|s| assert_eq!("lo", *s)); // New one still works
 }
+
+struct Document {
+    title: String,
+    words: Vec<String>,
+}
+
+#[test]
+pub fn project() {
+    let data = Rc::pin(Document {
+        title: "Hello".to_owned(),
+        words: vec!["Hello".to_owned(), "world".to_owned()],
+    });
+    let document: Selfie<Rc<Document>, Ref<Document>> = Selfie::new(data, |d| d);
+
+    let title = document.project::<Ref<str>, _>(|d| &d.title);
+    let word: Selfie<Rc<Document>, Ref<str>> = document.project(|d| d.words[1].as_str());
+    let nested: Selfie<Rc<Document>, Ref<str>> = word.project(|w| &w[1..]);
+
+    assert_eq!(4, Rc::strong_count(&Pin::into_inner(document.into_owned())));
+    assert_eq!("Hello", title.with_referential(|r| *r));
+    assert_eq!("world", word.with_referential(|r| *r));
+    assert_eq!("orld", nested.with_referential(|r| *r));
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "memmap2")]
+
+use selfie::mmap::MapFileError;
+use selfie::refs::Ref;
+use selfie::Selfie;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::str::Utf8Error;
+
+// These tests use real files and memory maps, which Miri's isolation does not allow.
+fn temp_file(name: &str, contents: &[u8]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("selfie-{}-{}", name, std::process::id()));
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+pub fn map_file() {
+    let path = temp_file("map-file", b"Hello, world!");
+
+    // SAFETY: the file is not modified while it is mapped.
+    let selfie: Selfie<_, Ref<str>> =
+        unsafe { Selfie::map_file(&path, |b| std::str::from_utf8(&b[7..])) }.unwrap();
+
+    assert_eq!("world!", selfie.with_referential(|r| *r));
+    assert_eq!(b"Hello, world!", selfie.owned());
+
+    let selfie = Box::new(selfie);
+    assert_eq!("world!", selfie.with_referential(|r| *r));
+
+    drop(selfie);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+pub fn map_file_handler_error() {
+    let path = temp_file("map-file-handler-error", b"Hello\xFF");
+
+    // SAFETY: the file is not modified while it is mapped.
+    let result: Result<Selfie<_, Ref<str>>, MapFileError<Utf8Error>> =
+        unsafe { Selfie::map_file(&path, |b| std::str::from_utf8(b)) };
+
+    match result {
+        Err(MapFileError::Handler(e)) => {
+            assert_eq!(5, e.error.valid_up_to());
+            assert_eq!(b"Hello\xFF", &*e.owned);
+        }
+        _ => panic!("Expected a handler error"),
+    }
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+pub fn map_file_io_error() {
+    let path = std::env::temp_dir().join("selfie-this-file-does-not-exist");
+
+    // SAFETY: the file does not exist.
+    let result: Result<Selfie<_, Ref<[u8]>>, MapFileError<()>> =
+        unsafe { Selfie::map_file(&path, |b| Ok(b)) };
+
+    match result {
+        Err(MapFileError::Io(e)) => assert_eq!(ErrorKind::NotFound, e.kind()),
+        _ => panic!("Expected an I/O error"),
+    }
+}
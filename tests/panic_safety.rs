@@ -0,0 +1,357 @@
+use selfie::refs::{Mut, RefType};
+use selfie::{Selfie, SelfieError, SelfieMut, ZippedSelfie};
+use stable_deref_trait::{CloneStableDeref, StableDeref};
+use std::cell::RefCell;
+use std::ops::Deref;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::pin::Pin;
+use std::rc::Rc;
+
+type Log = Rc<RefCell<Vec<&'static str>>>;
+
+struct Owner {
+    name: &'static str,
+    log: Log,
+}
+
+impl Drop for Owner {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(self.name);
+    }
+}
+
+fn owner(name: &'static str, log: &Log) -> Pin<Box<Owner>> {
+    Box::pin(Owner {
+        name,
+        log: log.clone(),
+    })
+}
+
+struct Referential<'a> {
+    name: &'static str,
+    owner: &'a Owner,
+}
+
+impl Drop for Referential<'_> {
+    fn drop(&mut self) {
+        // This reads from the owner, which must still be alive
+        self.owner.log.borrow_mut().push(self.name);
+    }
+}
+
+struct ReferentialRef;
+
+impl<'a> RefType<'a> for ReferentialRef {
+    type Ref = Referential<'a>;
+}
+
+fn referential(owner: &Owner) -> Referential<'_> {
+    Referential {
+        name: "referential",
+        owner,
+    }
+}
+
+#[test]
+pub fn new_panic() {
+    let log = Log::default();
+
+    catch_unwind(AssertUnwindSafe(|| {
+        let _: Selfie<Box<Owner>, ReferentialRef> = Selfie::new(owner("owner", &log), |o| {
+            let _referential = referential(o);
+            panic!("Haha")
+        });
+    }))
+    .unwrap_err();
+
+    assert_eq!(vec!["referential", "owner"], *log.borrow());
+}
+
+#[test]
+pub fn try_new_panic() {
+    let log = Log::default();
+
+    catch_unwind(AssertUnwindSafe(|| {
+        let _: Result<Selfie<Box<Owner>, ReferentialRef>, SelfieError<Box<Owner>, ()>> =
+            Selfie::try_new(owner("owner", &log), |o| {
+                let _referential = referential(o);
+                panic!("Haha")
+            });
+    }))
+    .unwrap_err();
+
+    assert_eq!(vec!["referential", "owner"], *log.borrow());
+}
+
+#[test]
+// Box owners are rejected by Miri, see panics_drop_referential_before_owner instead
+#[cfg_attr(miri, ignore)]
+pub fn map_panic() {
+    let log = Log::default();
+    let selfie: Selfie<Box<Owner>, ReferentialRef> =
+        Selfie::new(owner("owner", &log), |o| referential(o));
+
+    catch_unwind(AssertUnwindSafe(|| {
+        let _: Selfie<Box<Owner>, ReferentialRef> = selfie.map(|_, _| panic!("Haha"));
+    }))
+    .unwrap_err();
+
+    assert_eq!(vec!["referential", "owner"], *log.borrow());
+}
+
+#[test]
+// Box owners are rejected by Miri, see panics_drop_referential_before_owner instead
+#[cfg_attr(miri, ignore)]
+pub fn try_map_panic() {
+    let log = Log::default();
+    let selfie: Selfie<Box<Owner>, ReferentialRef> =
+        Selfie::new(owner("owner", &log), |o| referential(o));
+
+    catch_unwind(AssertUnwindSafe(|| {
+        let _ = selfie.try_map::<ReferentialRef, (), _>(|_, _| panic!("Haha"));
+    }))
+    .unwrap_err();
+
+    assert_eq!(vec!["referential", "owner"], *log.borrow());
+}
+
+#[test]
+pub fn map_cloned_panic() {
+    let log = Log::default();
+    let data = Rc::pin(Owner {
+        name: "owner",
+        log: log.clone(),
+    });
+    let selfie: Selfie<Rc<Owner>, ReferentialRef> = Selfie::new(data, |o| referential(o));
+
+    catch_unwind(AssertUnwindSafe(|| {
+        let _: Selfie<Rc<Owner>, ReferentialRef> = selfie.map_cloned(|_, o| {
+            let _referential = Referential {
+                name: "cloned referential",
+                owner: o,
+            };
+            panic!("Haha")
+        });
+    }))
+    .unwrap_err();
+
+    // The cloned owner has been dropped, but the original Selfie is left untouched
+    assert_eq!(vec!["cloned referential"], *log.borrow());
+    assert_eq!("referential", selfie.with_referential(|r| r.name));
+
+    let data = selfie.into_owned();
+    assert_eq!(1, Rc::strong_count(&Pin::into_inner(data)));
+    assert_eq!(
+        vec!["cloned referential", "referential", "owner"],
+        *log.borrow()
+    );
+}
+
+#[test]
+pub fn try_map_cloned_panic() {
+    let log = Log::default();
+    let data = Rc::pin(Owner {
+        name: "owner",
+        log: log.clone(),
+    });
+    let selfie: Selfie<Rc<Owner>, ReferentialRef> = Selfie::new(data, |o| referential(o));
+
+    catch_unwind(AssertUnwindSafe(|| {
+        let _ = selfie.try_map_cloned::<ReferentialRef, (), _>(|_, _| panic!("Haha"));
+    }))
+    .unwrap_err();
+
+    assert!(log.borrow().is_empty());
+    assert_eq!("referential", selfie.with_referential(|r| r.name));
+}
+
+struct MutReferential<'a> {
+    owner: &'a mut Owner,
+}
+
+impl Drop for MutReferential<'_> {
+    fn drop(&mut self) {
+        self.owner.log.borrow_mut().push("referential");
+    }
+}
+
+struct MutReferentialRef;
+
+impl<'a> RefType<'a> for MutReferentialRef {
+    type Ref = MutReferential<'a>;
+}
+
+#[test]
+// Box owners are rejected by Miri, see panics_drop_referential_before_owner instead
+#[cfg_attr(miri, ignore)]
+pub fn selfie_mut_map_panic() {
+    let log = Log::default();
+    let selfie: SelfieMut<Box<Owner>, MutReferentialRef> =
+        SelfieMut::new(owner("owner", &log), |o| MutReferential {
+            owner: Pin::into_inner(o),
+        });
+
+    catch_unwind(AssertUnwindSafe(|| {
+        let _: Selfie<Box<Owner>, Mut<Owner>> = selfie.map(|_, _| panic!("Haha"));
+    }))
+    .unwrap_err();
+
+    assert_eq!(vec!["referential", "owner"], *log.borrow());
+}
+
+#[test]
+// Box owners are rejected by Miri, see panics_drop_referential_before_owner instead
+#[cfg_attr(miri, ignore)]
+pub fn selfie_mut_try_map_panic() {
+    let log = Log::default();
+    let selfie: SelfieMut<Box<Owner>, MutReferentialRef> =
+        SelfieMut::new(owner("owner", &log), |o| MutReferential {
+            owner: Pin::into_inner(o),
+        });
+
+    catch_unwind(AssertUnwindSafe(|| {
+        let _ = selfie.try_map::<Mut<Owner>, (), _>(|_, _| panic!("Haha"));
+    }))
+    .unwrap_err();
+
+    assert_eq!(vec!["referential", "owner"], *log.borrow());
+}
+
+#[test]
+// Box owners are rejected by Miri, see panics_drop_referential_before_owner instead
+#[cfg_attr(miri, ignore)]
+pub fn zip_panic() {
+    let log = Log::default();
+    let first: Selfie<Box<Owner>, ReferentialRef> =
+        Selfie::new(owner("first owner", &log), |o| referential(o));
+    let second: Selfie<Box<Owner>, ReferentialRef> =
+        Selfie::new(owner("second owner", &log), |o| referential(o));
+
+    catch_unwind(AssertUnwindSafe(|| {
+        let _: ZippedSelfie<Box<Owner>, Box<Owner>, ReferentialRef> =
+            first.zip(second, |_, _, _, _| panic!("Haha"));
+    }))
+    .unwrap_err();
+
+    // Owners are dropped last, in the same order as when dropping a ZippedSelfie
+    assert_eq!(
+        vec!["referential", "referential", "first owner", "second owner"],
+        *log.borrow()
+    );
+}
+
+// An owner whose drops are recorded, including those of its clones. The data it points to is kept
+// alive by the test itself, so that Miri can check this test: it rejects deallocating data that
+// is still borrowed by a Selfie being passed by value, even when that borrow is never used again.
+#[derive(Clone)]
+struct TrackedOwner {
+    data: Rc<str>,
+    log: Log,
+}
+
+impl Deref for TrackedOwner {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.data
+    }
+}
+
+// SAFETY: the data is behind an Rc, which does not move when the owner is moved or cloned
+unsafe impl StableDeref for TrackedOwner {}
+// SAFETY: see above
+unsafe impl CloneStableDeref for TrackedOwner {}
+
+impl Drop for TrackedOwner {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push("owner");
+    }
+}
+
+struct TrackedReferential<'a> {
+    data: &'a str,
+    log: Log,
+}
+
+impl Drop for TrackedReferential<'_> {
+    fn drop(&mut self) {
+        // This reads from the owner, which must still be alive
+        assert_eq!("Hello, world!", self.data);
+        self.log.borrow_mut().push("referential");
+    }
+}
+
+struct TrackedReferentialRef;
+
+impl<'a> RefType<'a> for TrackedReferentialRef {
+    type Ref = TrackedReferential<'a>;
+}
+
+type TrackedSelfie = Selfie<'static, TrackedOwner, TrackedReferentialRef>;
+
+fn tracked_owner(data: &Rc<str>, log: &Log) -> Pin<TrackedOwner> {
+    Pin::new(TrackedOwner {
+        data: data.clone(),
+        log: log.clone(),
+    })
+}
+
+fn tracked_referential<'a>(data: &'a str, log: &Log) -> TrackedReferential<'a> {
+    TrackedReferential {
+        data,
+        log: log.clone(),
+    }
+}
+
+fn assert_panic_drop_order(log: &Log, operation: impl FnOnce()) {
+    catch_unwind(AssertUnwindSafe(operation)).unwrap_err();
+    assert_eq!(vec!["referential", "owner"], *log.borrow());
+}
+
+#[test]
+pub fn panics_drop_referential_before_owner() {
+    let data: Rc<str> = Rc::from("Hello, world!");
+
+    let log = Log::default();
+    assert_panic_drop_order(&log, || {
+        let _: TrackedSelfie = Selfie::new(tracked_owner(&data, &log), |d| {
+            let _referential = tracked_referential(d, &log);
+            panic!("Haha")
+        });
+    });
+
+    let log = Log::default();
+    assert_panic_drop_order(&log, || {
+        let _: Result<TrackedSelfie, SelfieError<TrackedOwner, ()>> =
+            Selfie::try_new(tracked_owner(&data, &log), |d| {
+                let _referential = tracked_referential(d, &log);
+                panic!("Haha")
+            });
+    });
+
+    let log = Log::default();
+    let selfie: TrackedSelfie =
+        Selfie::new(tracked_owner(&data, &log), |d| tracked_referential(d, &log));
+    assert_panic_drop_order(&log, || {
+        let _: TrackedSelfie = selfie.map(|_, _| panic!("Haha"));
+    });
+
+    let log = Log::default();
+    let selfie: TrackedSelfie =
+        Selfie::new(tracked_owner(&data, &log), |d| tracked_referential(d, &log));
+    assert_panic_drop_order(&log, || {
+        let _ = selfie.try_map::<TrackedReferentialRef, (), _>(|_, _| panic!("Haha"));
+    });
+
+    // Here, the referential and owner being dropped are the cloned ones
+    let log = Log::default();
+    let selfie: TrackedSelfie =
+        Selfie::new(tracked_owner(&data, &log), |d| tracked_referential(d, &log));
+    assert_panic_drop_order(&log, || {
+        let _: TrackedSelfie = selfie.map_cloned(|_, d| {
+            let _referential = tracked_referential(d, &log);
+            panic!("Haha")
+        });
+    });
+    assert_eq!("Hello, world!", selfie.with_referential(|r| r.data));
+}
@@ -0,0 +1,88 @@
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use selfie::refs::Ref;
+use selfie::{ArcSelfie, RcSelfie, Selfie};
+use std::cell::Cell;
+use std::pin::Pin;
+use std::thread;
+
+struct DropCounter<'a> {
+    value: String,
+    drops: &'a Cell<usize>,
+}
+
+impl Drop for DropCounter<'_> {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+    }
+}
+
+#[test]
+pub fn rc_clones_share_referential() {
+    let data: Pin<Box<String>> = Box::pin("Hello, world!".to_owned());
+    let selfie: RcSelfie<Box<String>, Ref<str>> = RcSelfie::new(Selfie::new(data, |s| &s[0..5]));
+    let cloned = selfie.clone();
+
+    assert!(RcSelfie::ptr_eq(&selfie, &cloned));
+    assert_eq!(2, RcSelfie::strong_count(&selfie));
+
+    let first = selfie.with_referential(|r| r.as_ptr());
+    let second = cloned.with_referential(|r| r.as_ptr());
+    assert_eq!(first, second);
+}
+
+#[test]
+pub fn rc_drops_once() {
+    let drops = Cell::new(0);
+    let data = Box::pin(DropCounter {
+        value: "Hello, world!".to_owned(),
+        drops: &drops,
+    });
+    let selfie: RcSelfie<Box<DropCounter>, Ref<str>> = Selfie::new(data, |d| &d.value[0..5]).into();
+
+    let weak = RcSelfie::downgrade(&selfie);
+    let cloned = weak.upgrade().unwrap();
+
+    drop(selfie);
+    assert_eq!(0, drops.get());
+    assert_eq!("Hello", weak.upgrade().unwrap().with_referential(|r| *r));
+
+    drop(cloned);
+    assert_eq!(1, drops.get());
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+pub fn rc_try_unwrap() {
+    let data: Pin<Box<String>> = Box::pin("Hello, world!".to_owned());
+    let selfie: RcSelfie<Box<String>, Ref<str>> = Selfie::new(data, |s| &s[0..5]).into();
+    let cloned = selfie.clone();
+
+    let selfie = RcSelfie::try_unwrap(selfie).unwrap_err();
+    drop(cloned);
+
+    let selfie = RcSelfie::try_unwrap(selfie).unwrap();
+    assert_eq!("Hello, world!", selfie.into_owned().as_str());
+}
+
+#[test]
+pub fn arc_is_shared_across_threads() {
+    let data: Pin<Box<String>> = Box::pin("Hello, world!".to_owned());
+    let selfie: ArcSelfie<Box<String>, Ref<str>> = Selfie::new(data, |s| &s[0..5]).into();
+    let weak = ArcSelfie::downgrade(&selfie);
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let selfie = selfie.clone();
+            thread::spawn(move || selfie.with_referential(|r| r.to_string()))
+        })
+        .collect();
+
+    for handle in handles {
+        assert_eq!("Hello", handle.join().unwrap());
+    }
+
+    assert_eq!(1, ArcSelfie::strong_count(&selfie));
+    drop(selfie);
+    assert!(weak.upgrade().is_none());
+}
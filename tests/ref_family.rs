@@ -0,0 +1,85 @@
+use selfie::refs::{Covariant, Ref, RefFamily, RefType};
+use selfie::{RcSelfie, Selfie, SelfieMut};
+use std::pin::Pin;
+
+#[derive(Debug, PartialEq)]
+struct Words<'a> {
+    first: &'a str,
+    rest: &'a str,
+}
+
+struct WordsStandIn;
+
+impl RefFamily for WordsStandIn {
+    type Ref<'a> = Words<'a>;
+}
+
+impl Covariant for WordsStandIn {
+    fn shorten_ref<'short, 'long: 'short>(
+        referential: &'short Words<'long>,
+    ) -> &'short Words<'short> {
+        referential
+    }
+}
+
+fn words(s: &str) -> Words<'_> {
+    let (first, rest) = s.split_once(' ').unwrap_or((s, ""));
+    Words { first, rest }
+}
+
+#[test]
+fn gat_stand_in() {
+    let data: Pin<String> = Pin::new("Hello dear world".to_owned());
+    let selfie: Selfie<String, WordsStandIn> = Selfie::new(data, |s| words(s));
+
+    assert_eq!("Hello", selfie.with_referential(|w| w.first));
+    assert_eq!("dear world", selfie.get().rest);
+
+    let rest = selfie.map_ref(|w, _| w.rest);
+    assert_eq!("dear world", rest.with_referential(|r| *r));
+}
+
+#[test]
+// Miri reports moving a Box owner as invalidating the referential, like in cascading_mut
+#[cfg_attr(miri, ignore)]
+fn gat_stand_in_mut() {
+    struct SliceHalves;
+
+    impl RefFamily for SliceHalves {
+        type Ref<'a> = (&'a mut [u8], &'a mut [u8]);
+    }
+
+    let data: Pin<Box<[u8; 4]>> = Box::pin([1, 2, 3, 4]);
+    let mut selfie: SelfieMut<Box<[u8; 4]>, SliceHalves> =
+        SelfieMut::new(data, |a| Pin::into_inner(a).split_at_mut(2));
+
+    selfie.with_referential_mut(|(left, right)| left.swap_with_slice(right));
+    assert_eq!(&[3, 4, 1, 2], &*selfie.into_owned());
+}
+
+#[test]
+fn gat_stand_in_rc() {
+    let data: Pin<String> = Pin::new("Hello world".to_owned());
+    let selfie: RcSelfie<String, WordsStandIn> = Selfie::new(data, |s| words(s)).into();
+
+    let cloned = selfie.clone();
+    assert_eq!("world", cloned.with_referential(|w| w.rest));
+}
+
+// Generic code written against RefType keeps working with Selfie
+fn len_of<R>(selfie: &Selfie<String, R>) -> usize
+where
+    R: for<'this> RefType<'this>,
+    for<'this> <R as RefType<'this>>::Ref: AsRef<str>,
+{
+    selfie.with_referential(|r| r.as_ref().len())
+}
+
+#[test]
+fn ref_type_bounds() {
+    let data: Pin<String> = Pin::new("Hello world".to_owned());
+    let selfie: Selfie<String, Ref<str>> = Selfie::new(data, |s| &s[6..]);
+
+    // Older compilers cannot infer R through the higher-ranked projection bound
+    assert_eq!(5, len_of::<Ref<str>>(&selfie));
+}
@@ -0,0 +1,133 @@
+use selfie::refs::Ref;
+use selfie::rt::triple_buffer;
+use selfie::Selfie;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+struct Preset {
+    name: String,
+    gain: u32,
+}
+
+type PresetSelfie = Selfie<'static, Box<Preset>, Ref<str>>;
+
+fn preset(name: &str, gain: u32) -> PresetSelfie {
+    let preset = Box::pin(Preset {
+        name: name.to_owned(),
+        gain,
+    });
+
+    Selfie::new(preset, |p| p.name.as_str())
+}
+
+#[test]
+pub fn consumer_starts_empty() {
+    let (_producer, mut consumer) = triple_buffer::<PresetSelfie>();
+
+    assert!(!consumer.update());
+    assert!(consumer.get().is_none());
+    assert_eq!(None, consumer.with_referential(|r| r.len()));
+}
+
+#[test]
+pub fn consumer_gets_latest_value() {
+    let (mut producer, mut consumer) = triple_buffer::<PresetSelfie>();
+
+    assert!(producer.publish(preset("first", 1)).is_none());
+    assert!(consumer.update());
+    assert_eq!(Some("first"), consumer.with_referential(|r| *r));
+    assert!(!consumer.update());
+
+    // The second value is superseded by the third one before being picked up
+    assert!(producer.publish(preset("second", 2)).is_none());
+    let retired = producer.publish(preset("third", 3)).unwrap();
+    assert_eq!("second", retired.with_referential(|r| *r));
+
+    assert!(consumer.update());
+    assert_eq!(Some("third"), consumer.with_referential(|r| *r));
+    assert_eq!(3, consumer.get().unwrap().owned().gain);
+
+    // The first value has been retired by the consumer
+    let retired = producer.collect().unwrap();
+    assert_eq!("first", retired.with_referential(|r| *r));
+    assert!(producer.collect().is_none());
+}
+
+#[test]
+pub fn retired_values_are_returned_on_publish() {
+    let (mut producer, mut consumer) = triple_buffer::<PresetSelfie>();
+
+    assert!(producer.publish(preset("first", 1)).is_none());
+    assert!(consumer.update());
+    assert!(producer.publish(preset("second", 2)).is_none());
+    assert!(consumer.update());
+
+    let retired = producer.publish(preset("third", 3)).unwrap();
+    assert_eq!("first", retired.with_referential(|r| *r));
+    assert_eq!(Some("second"), consumer.with_referential(|r| *r));
+}
+
+struct DropCounter<'a>(&'a AtomicUsize);
+
+impl Drop for DropCounter<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+pub fn values_are_handed_over_across_threads() {
+    const COUNT: u32 = 1000;
+
+    // Box owners are rejected by Miri once moved, which would keep it from checking this for races
+    let (mut producer, mut consumer) = triple_buffer::<Selfie<Arc<Preset>, Ref<u32>>>();
+
+    let worker = thread::spawn(move || {
+        let mut retired = 0;
+
+        for i in 1..=COUNT {
+            let preset = Arc::pin(Preset {
+                name: i.to_string(),
+                gain: i,
+            });
+
+            retired += producer.publish(Selfie::new(preset, |p| &p.gain)).is_some() as u32;
+            retired += producer.collect().is_some() as u32;
+        }
+
+        (producer, retired)
+    });
+
+    let mut last = 0;
+    while last < COUNT {
+        if consumer.update() {
+            let current = consumer.with_referential(|r| **r).unwrap();
+            assert!(current > last);
+            assert_eq!(current.to_string(), consumer.get().unwrap().owned().name);
+            last = current;
+        }
+    }
+
+    let (mut producer, mut retired) = worker.join().unwrap();
+    retired += producer.collect().is_some() as u32;
+
+    // Every value but the last one (still held by the consumer) was retired on the producer side
+    assert_eq!(COUNT - 1, retired);
+}
+
+#[test]
+pub fn remaining_values_are_dropped_with_buffer() {
+    let dropped = AtomicUsize::new(0);
+    let (mut producer, mut consumer) = triple_buffer::<DropCounter>();
+
+    assert!(producer.publish(DropCounter(&dropped)).is_none());
+    assert!(consumer.update());
+    assert!(producer.publish(DropCounter(&dropped)).is_none());
+
+    drop(producer);
+    assert_eq!(0, dropped.load(Ordering::SeqCst));
+
+    drop(consumer);
+    assert_eq!(2, dropped.load(Ordering::SeqCst));
+}
@@ -0,0 +1,134 @@
+#![cfg(feature = "std")]
+
+use selfie::refs::Ref;
+use selfie::{ArcSelfie, Selfie, SharedSelfie};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::thread;
+
+struct Config {
+    name: String,
+    revision: u32,
+}
+
+type ConfigSelfie = Selfie<'static, Arc<Config>, Ref<u32>>;
+
+fn config(revision: u32) -> ConfigSelfie {
+    let config: Pin<Arc<Config>> = Arc::pin(Config {
+        name: format!("revision {}", revision),
+        revision,
+    });
+
+    Selfie::new(config, |c| &c.revision)
+}
+
+#[test]
+pub fn load_sees_latest_store() {
+    let shared = SharedSelfie::new(config(1));
+    assert_eq!(1, shared.load().with_referential(|r| **r));
+
+    shared.store(config(2));
+    let guard = shared.load();
+    assert_eq!(2, guard.with_referential(|r| **r));
+    assert_eq!("revision 2", guard.owned().name);
+}
+
+#[test]
+pub fn handles_keep_previous_value_alive() {
+    let shared: SharedSelfie<Arc<Config>, Ref<u32>> = config(1).into();
+    let first = shared.load();
+    let owned = first.owned() as *const Config;
+
+    let previous = shared.swap(config(2));
+    assert!(ArcSelfie::ptr_eq(&first, &previous));
+    assert!(!ArcSelfie::ptr_eq(&first, &shared.load()));
+
+    drop(previous);
+    assert_eq!(1, first.with_referential(|r| **r));
+    assert_eq!(owned, first.owned() as *const Config);
+
+    let cloned = first.clone();
+    assert!(ArcSelfie::ptr_eq(&first, &cloned));
+}
+
+#[test]
+pub fn readers_see_consistent_values_across_threads() {
+    let shared = Arc::new(SharedSelfie::new(config(0)));
+
+    let readers: Vec<_> = (0..4)
+        .map(|_| {
+            let shared = shared.clone();
+            thread::spawn(move || {
+                let mut last = 0;
+
+                while last < 100 {
+                    let guard = shared.load();
+                    let revision = guard.with_referential(|r| **r);
+
+                    assert!(revision >= last);
+                    assert_eq!(format!("revision {}", revision), guard.owned().name);
+                    last = revision;
+                }
+            })
+        })
+        .collect();
+
+    for revision in 1..=100 {
+        shared.store(config(revision));
+    }
+
+    for reader in readers {
+        reader.join().unwrap();
+    }
+}
+
+#[test]
+pub fn drop_releases_current_value() {
+    let config = Arc::new(Config {
+        name: "revision 1".to_owned(),
+        revision: 1,
+    });
+    let selfie: ConfigSelfie = Selfie::new(Pin::new(config.clone()), |c| &c.revision);
+
+    let shared = SharedSelfie::new(selfie);
+    let handle = shared.load();
+    assert_eq!(2, Arc::strong_count(&config));
+
+    drop(shared);
+    assert_eq!(2, Arc::strong_count(&config));
+
+    drop(handle);
+    assert_eq!(1, Arc::strong_count(&config));
+}
+
+#[test]
+pub fn concurrent_stores_release_every_value() {
+    let first = Arc::new(Config {
+        name: "revision 0".to_owned(),
+        revision: 0,
+    });
+    let selfie: ConfigSelfie = Selfie::new(Pin::new(first.clone()), |c| &c.revision);
+    let shared = Arc::new(SharedSelfie::new(selfie));
+
+    let threads: Vec<_> = (0..2)
+        .map(|_| {
+            let shared = shared.clone();
+            let first = first.clone();
+            thread::spawn(move || {
+                for _ in 0..20 {
+                    shared.store(Selfie::new(Pin::new(first.clone()), |c| &c.revision));
+                    assert_eq!(0, shared.load().with_referential(|r| **r));
+                }
+            })
+        })
+        .collect();
+
+    for thread in threads {
+        thread.join().unwrap();
+    }
+
+    // Only the current value still holds a reference to the config
+    assert_eq!(2, Arc::strong_count(&first));
+    drop(shared);
+    assert_eq!(1, Arc::strong_count(&first));
+}
@@ -118,7 +118,7 @@ pub fn drops() {
     drop(data);
 }
 
-fn all_but_first_char(x: &RefCell<String>) -> Selfie<::core::cell::Ref<String>, Ref<str>> {
+fn all_but_first_char(x: &RefCell<String>) -> Selfie<'_, ::core::cell::Ref<'_, String>, Ref<str>> {
     let x = Pin::new(x.borrow());
     Selfie::new(x, |s| &s[1..])
 }
@@ -134,3 +134,41 @@ pub fn refcell() {
 
     assert!(refcell.try_borrow_mut().is_ok());
 }
+
+struct ParseError<'a> {
+    token: &'a str,
+}
+
+struct ParseErrorRef;
+
+impl<'a> RefType<'a> for ParseErrorRef {
+    type Ref = ParseError<'a>;
+}
+
+fn first_word(input: &str) -> Result<&str, ParseError<'_>> {
+    match input.split_whitespace().next() {
+        Some(word) if word.chars().all(char::is_alphabetic) => Ok(word),
+        Some(token) => Err(ParseError { token }),
+        None => Err(ParseError { token: input }),
+    }
+}
+
+#[test]
+pub fn try_new_borrowed_err() {
+    let data = Pin::new("Hello world".to_owned());
+    let result: Result<Selfie<String, Ref<str>>, Selfie<String, ParseErrorRef>> =
+        Selfie::try_new_borrowed_err(data, |s| first_word(s));
+    assert_eq!("Hello", result.ok().unwrap().with_referential(|r| *r));
+
+    let data = Pin::new("H3llo world".to_owned());
+    let result: Result<Selfie<String, Ref<str>>, Selfie<String, ParseErrorRef>> =
+        Selfie::try_new_borrowed_err(data, |s| first_word(s));
+    let error = result.err().unwrap();
+
+    // Moving the error has no consequence
+    let error = Box::new(error);
+    assert_eq!("H3llo", error.with_referential(|e| e.token));
+
+    let data = error.into_owned();
+    assert_eq!("H3llo world", &*data);
+}
@@ -0,0 +1,91 @@
+use selfie::refs::*;
+use std::cell::{Cell, UnsafeCell};
+use std::marker::PhantomData;
+use std::ops::{Range, RangeInclusive};
+use std::ptr::NonNull;
+
+// These only need to compile: they check the referential types can be shortened to a smaller
+// lifetime, like their non-stand-in counterparts.
+
+fn shorten_slice_iter<'s, 'l: 's>(
+    r: <SliceIter<u8> as RefType<'l>>::Ref,
+) -> <SliceIter<u8> as RefType<'s>>::Ref {
+    r
+}
+
+fn shorten_slice_iter_mut<'s, 'l: 's>(
+    r: <SliceIterMut<u8> as RefType<'l>>::Ref,
+) -> <SliceIterMut<u8> as RefType<'s>>::Ref {
+    r
+}
+
+fn shorten_chars<'s, 'l: 's>(r: <Chars as RefType<'l>>::Ref) -> <Chars as RefType<'s>>::Ref {
+    r
+}
+
+fn shorten_char_indices<'s, 'l: 's>(
+    r: <CharIndices as RefType<'l>>::Ref,
+) -> <CharIndices as RefType<'s>>::Ref {
+    r
+}
+
+fn shorten_phantom<'s, 'l: 's>(
+    r: <PhantomData<Ref<str>> as RefType<'l>>::Ref,
+) -> <PhantomData<Ref<str>> as RefType<'s>>::Ref {
+    r
+}
+
+fn shorten_range<'s, 'l: 's>(
+    r: <Range<Ref<u8>> as RefType<'l>>::Ref,
+) -> <Range<Ref<u8>> as RefType<'s>>::Ref {
+    r
+}
+
+fn shorten_range_inclusive<'s, 'l: 's>(
+    r: <RangeInclusive<Ref<u8>> as RefType<'l>>::Ref,
+) -> <RangeInclusive<Ref<u8>> as RefType<'s>>::Ref {
+    r
+}
+
+fn shorten_cell_ref<'s, 'l: 's>(
+    r: <Ref<Cell<u8>> as RefType<'l>>::Ref,
+) -> <Ref<Cell<u8>> as RefType<'s>>::Ref {
+    r
+}
+
+fn shorten_unsafe_cell_ref<'s, 'l: 's>(
+    r: <Ref<UnsafeCell<u8>> as RefType<'l>>::Ref,
+) -> <Ref<UnsafeCell<u8>> as RefType<'s>>::Ref {
+    r
+}
+
+fn shorten_non_null<'s, 'l: 's>(
+    r: <NonNull<u8> as RefType<'l>>::Ref,
+) -> <NonNull<u8> as RefType<'s>>::Ref {
+    r
+}
+
+#[test]
+pub fn covariant_stand_ins() {
+    let values = [1u8, 2, 3];
+    let string = "Hello".to_owned();
+
+    assert_eq!(3, shorten_slice_iter(values.iter()).count());
+    assert_eq!(3, shorten_slice_iter_mut(values.clone().iter_mut()).count());
+    assert_eq!(5, shorten_chars(string.chars()).count());
+    assert_eq!(5, shorten_char_indices(string.char_indices()).count());
+    assert_eq!(PhantomData, shorten_phantom(PhantomData));
+    assert_eq!(&1..&3, shorten_range(&values[0]..&values[2]));
+    assert_eq!(&1..=&3, shorten_range_inclusive(&values[0]..=&values[2]));
+
+    let cell = Cell::new(42);
+    assert_eq!(42, shorten_cell_ref(&cell).get());
+    let unsafe_cell = UnsafeCell::new(42);
+    assert_eq!(
+        unsafe_cell.get(),
+        shorten_unsafe_cell_ref(&unsafe_cell).get()
+    );
+
+    let ptr = NonNull::from(&values[0]);
+    assert_eq!(ptr, shorten_non_null(ptr));
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "yoke")]
+
+use selfie::refs::Ref;
+use selfie::Selfie;
+use std::pin::Pin;
+use yoke::Yoke;
+
+#[test]
+pub fn selfie_to_yoke() {
+    let data = Pin::new("Hello, world!".to_owned());
+    let selfie: Selfie<String, Ref<str>> = Selfie::new(data, |s| &s[0..5]);
+
+    let yoke: Yoke<&'static str, String> = selfie.into();
+    assert_eq!("Hello", *yoke.get());
+    assert_eq!("Hello, world!", yoke.backing_cart());
+
+    let yoke = Box::new(yoke);
+    assert_eq!("Hello", *yoke.get());
+}
+
+#[test]
+// Miri reports yoke moving its Box cart as invalidating the yokeable, much like in cascading_mut
+#[cfg_attr(miri, ignore)]
+pub fn yoke_to_selfie() {
+    let yoke: Yoke<Option<&'static str>, Box<str>> =
+        Yoke::attach_to_cart("Hello, world!".into(), |s| s.get(7..));
+
+    let selfie: Selfie<Box<str>, Option<Ref<str>>> = yoke.into();
+    assert_eq!(Some("world!"), selfie.with_referential(|r| *r));
+    assert_eq!("Hello, world!", selfie.owned());
+
+    let selfie = Box::new(selfie);
+    assert_eq!(Some("world!"), selfie.with_referential(|r| *r));
+}
+
+#[test]
+// Miri reports moving a Box owner as invalidating the referential, like in cascading_mut
+#[cfg_attr(miri, ignore)]
+pub fn round_trip() {
+    let data = Box::pin([1u8, 2, 3, 4]);
+    let selfie: Selfie<Box<[u8; 4]>, Ref<[u8]>> = Selfie::new(data, |d| &d[1..3]);
+
+    let yoke: Yoke<&'static [u8], Box<[u8; 4]>> = selfie.into();
+    let yoke = yoke.map_project::<&'static [u8], _>(|s, _| &s[1..]);
+
+    let selfie: Selfie<Box<[u8; 4]>, Ref<[u8]>> = yoke.into();
+    assert_eq!(&[3], selfie.with_referential(|r| *r));
+    assert_eq!(&[1, 2, 3, 4], selfie.owned());
+}
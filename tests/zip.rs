@@ -0,0 +1,113 @@
+use selfie::refs::{Ref, RefType};
+use selfie::{Selfie, ZippedSelfie};
+use std::cell::RefCell;
+use std::pin::Pin;
+use std::rc::Rc;
+
+struct Pair<'a> {
+    first: &'a str,
+    second: &'a str,
+}
+
+struct PairRef;
+
+impl<'a> RefType<'a> for PairRef {
+    type Ref = Pair<'a>;
+}
+
+#[test]
+// Miri reports moving a Box owner as invalidating the referential, like in cascading_mut
+#[cfg_attr(miri, ignore)]
+pub fn simple_zip() {
+    let first: Selfie<String, Ref<str>> =
+        Selfie::new(Pin::new("Hello, world!".to_owned()), |s| &s[0..5]);
+    let second: Selfie<Box<str>, Ref<str>> =
+        Selfie::new(Pin::new("Goodbye, world!".into()), |s| &s[0..7]);
+
+    let mut zipped: ZippedSelfie<String, Box<str>, PairRef> =
+        first.zip(second, |first, second, _, _| Pair { first, second });
+
+    assert_eq!(("Hello, world!", "Goodbye, world!"), zipped.owned());
+    assert_eq!("Hello", zipped.with_referential(|p| p.first));
+    assert_eq!("Goodbye", zipped.with_referential(|p| p.second));
+
+    zipped.with_referential_mut(|p| std::mem::swap(&mut p.first, &mut p.second));
+    assert_eq!("Goodbye", zipped.with_referential(|p| p.first));
+
+    let (first, second) = zipped.into_owned();
+    assert_eq!("Hello, world!", &*first);
+    assert_eq!("Goodbye, world!", &*second);
+}
+
+#[test]
+pub fn zip_with_owned() {
+    let first: Selfie<String, Ref<str>> =
+        Selfie::new(Pin::new("Hello, world!".to_owned()), |s| &s[0..5]);
+    let second: Selfie<String, Ref<str>> = Selfie::new(Pin::new("Goodbye".to_owned()), |s| s);
+
+    let zipped: ZippedSelfie<String, String, PairRef> =
+        first.zip(second, |_, second, first_owned, _| Pair {
+            first: &first_owned[7..],
+            second,
+        });
+
+    assert_eq!("world!", zipped.with_referential(|p| p.first));
+    assert_eq!("Goodbye", zipped.with_referential(|p| p.second));
+}
+
+struct Recorder {
+    name: &'static str,
+    log: Rc<RefCell<Vec<&'static str>>>,
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(self.name);
+    }
+}
+
+struct Referential<'a> {
+    first: &'a Recorder,
+}
+
+impl Drop for Referential<'_> {
+    fn drop(&mut self) {
+        self.first.log.borrow_mut().push("referential");
+    }
+}
+
+struct ReferentialRef;
+
+impl<'a> RefType<'a> for ReferentialRef {
+    type Ref = Referential<'a>;
+}
+
+#[test]
+// Miri reports moving a Box owner as invalidating the referential, like in cascading_mut
+#[cfg_attr(miri, ignore)]
+pub fn zip_drop_order() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    let first = Box::pin(Recorder {
+        name: "first owner",
+        log: log.clone(),
+    });
+    let second = Box::pin(Recorder {
+        name: "second owner",
+        log: log.clone(),
+    });
+
+    let first: Selfie<Box<Recorder>, Ref<Recorder>> = Selfie::new(first, |r| r);
+    let second: Selfie<Box<Recorder>, Ref<Recorder>> = Selfie::new(second, |r| r);
+
+    let zipped: ZippedSelfie<Box<Recorder>, Box<Recorder>, ReferentialRef> =
+        first.zip(second, |first, _, _, _| Referential { first });
+
+    assert!(log.borrow().is_empty());
+    drop(zipped);
+
+    assert_eq!(
+        vec!["referential", "first owner", "second owner"],
+        *log.borrow()
+    );
+}